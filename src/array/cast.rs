@@ -0,0 +1,207 @@
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec, vec::Vec};
+use core::{any, fmt::Debug};
+
+use num_complex::Complex;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+use num_traits::{AsPrimitive, Bounded, Num, NumCast, ToPrimitive, Zero};
+
+use super::ArrayBase;
+use crate::{storage::Storage, Array, Dimensionality, Error, NDArray, Order, Result, RowMajor};
+
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+{
+    pub fn astype<U>(&self) -> Array<U, D, O>
+    where
+        <S as Storage>::Elem: AsPrimitive<U>,
+        U: Copy + 'static,
+    {
+        self.map(|x| x.as_())
+    }
+
+    pub fn astype_saturating<U>(&self) -> Array<U, D, O>
+    where
+        <S as Storage>::Elem: ToPrimitive,
+        U: Bounded + Clone + NumCast + Zero,
+    {
+        self.map(|x| {
+            U::from(x.clone()).unwrap_or_else(|| match x.to_f64() {
+                Some(x) if x > 0. => U::max_value(),
+                Some(x) if x < 0. => U::min_value(),
+                _ => U::zero(),
+            })
+        })
+    }
+
+    pub fn to_complex<T>(&self) -> Array<Complex<T>, D, O>
+    where
+        <S as Storage>::Elem: Into<T>,
+        T: Clone + Num,
+    {
+        self.map(|x| Complex::new(x.clone().into(), T::zero()))
+    }
+
+    pub fn try_astype<U>(&self) -> Result<Array<U, D, O>>
+    where
+        <S as Storage>::Elem: Debug + ToPrimitive,
+        U: Clone + NumCast,
+    {
+        let data = self
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                U::from(x.clone()).ok_or_else(|| {
+                    Error::Dtype(format!(
+                        "cannot cast {:?} at index {:?} to {}",
+                        x,
+                        unravel_index(self.shape.as_ref(), i),
+                        any::type_name::<U>()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Array::from_vec_with_order::<RowMajor>(self.shape.clone(), data)
+    }
+}
+
+fn unravel_index(shape: &[usize], mut index: usize) -> Vec<usize> {
+    let mut indices = vec![0; shape.len()];
+    for (i, &dim) in indices.iter_mut().zip(shape).rev() {
+        *i = index % dim;
+        index /= dim;
+    }
+    indices
+}
+
+impl<D, O, S, T> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage<Elem = Complex<T>>,
+    T: Clone + Num,
+{
+    pub fn conj(&self) -> Array<Complex<T>, D, O>
+    where
+        T: core::ops::Neg<Output = T>,
+    {
+        self.map(Complex::conj)
+    }
+
+    pub fn im(&self) -> Array<T, D, O> {
+        self.map(|x| x.im.clone())
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn norm(&self) -> Array<T, D, O>
+    where
+        T: Float,
+    {
+        self.map(|x| x.norm())
+    }
+
+    pub fn re(&self) -> Array<T, D, O> {
+        self.map(|x| x.re.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use num_complex::Complex;
+
+    use crate::{array, Array, Error, NDArray, NDArrayOwned, Result};
+
+    #[test]
+    fn astype() -> Result<()> {
+        let a = Array::from(vec![-1.5_f64, 0.7, 300., f64::NAN]).into_shape([2, 2])?;
+
+        assert_eq!(
+            a.astype::<i32>().iter().cloned().collect::<Vec<_>>(),
+            vec![-1, 0, 300, 0]
+        );
+        assert_eq!(
+            a.astype::<u8>().iter().cloned().collect::<Vec<_>>(),
+            vec![0, 0, 255, 0]
+        );
+        assert_eq!(array!([1_u8, 255]).astype::<i8>().shape(), &[2]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_astype() {
+        let a = array!([1_i32, 200, 300]);
+
+        assert_eq!(
+            a.try_astype::<i16>()
+                .unwrap()
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![1, 200, 300]
+        );
+        assert!(matches!(
+            a.try_astype::<u8>(),
+            Err(Error::Dtype(message)) if message == "cannot cast 300 at index [2] to u8"
+        ));
+        assert!(matches!(
+            Array::from(vec![1.5_f64, 2., 3., f64::NAN])
+                .into_shape([2, 2])
+                .unwrap()
+                .try_astype::<i64>(),
+            Err(Error::Dtype(message)) if message.contains("NaN at index [1, 1]")
+        ));
+    }
+
+    #[test]
+    fn astype_saturating() {
+        let a = Array::from(vec![-1e10_f64, -1.5, 300., f64::NAN, f64::INFINITY]);
+
+        assert_eq!(
+            a.astype_saturating::<i16>()
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![i16::MIN, -1, 300, 0, i16::MAX]
+        );
+        assert_eq!(
+            Array::from(vec![-1_i32, 1000])
+                .astype_saturating::<u8>()
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![0, 255]
+        );
+    }
+
+    #[test]
+    fn complex_parts() -> Result<()> {
+        let a = array!([1_f32, 2.]).to_complex::<f64>();
+
+        assert_eq!(
+            a.iter().cloned().collect::<Vec<_>>(),
+            vec![Complex::new(1., 0.), Complex::new(2., 0.)]
+        );
+
+        let b =
+            Array::from(vec![Complex::new(3_f64, 4.), Complex::new(0., -1.)]).into_shape([2])?;
+
+        assert_eq!(b.re().iter().cloned().collect::<Vec<_>>(), vec![3., 0.]);
+        assert_eq!(b.im().iter().cloned().collect::<Vec<_>>(), vec![4., -1.]);
+        assert_eq!(
+            b.conj().iter().cloned().collect::<Vec<_>>(),
+            vec![Complex::new(3., -4.), Complex::new(0., 1.)]
+        );
+        #[cfg(any(feature = "std", feature = "libm"))]
+        assert_eq!(b.norm().iter().cloned().collect::<Vec<_>>(), vec![5., 1.]);
+
+        Ok(())
+    }
+}
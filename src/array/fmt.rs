@@ -1,48 +1,597 @@
-use core::{any, default::Default, fmt};
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+use core::{any, default::Default, fmt, fmt::Write, iter::Peekable, num::Wrapping};
+
+use num_complex::Complex;
+#[cfg(feature = "std")]
+use std::sync::RwLock;
 
 use super::ArrayBase;
 use crate::{dyn_s, storage::Storage, Dimensionality, NDArray, Order};
 
-const NUM_EDGE_ELEMENTS: usize = 3;
-const TRUNCATION_THRESHOLD: usize = 1_000;
+#[cfg(feature = "std")]
+static PRINT_OPTIONS: RwLock<PrintOptions> = RwLock::new(PrintOptions::new());
 
-struct FormatOption {
-    num_edge_elements: usize,
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Sign {
+    Negative,
+    Plus,
+    Space,
 }
 
-impl Default for FormatOption {
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PrintOptions {
+    pub(crate) precision: Option<usize>,
+    threshold: usize,
+    edge_items: usize,
+    line_width: usize,
+    suppress_small: bool,
+    sign: Sign,
+}
+
+impl Default for PrintOptions {
     fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrintOptions {
+    pub const fn new() -> Self {
         Self {
-            num_edge_elements: NUM_EDGE_ELEMENTS,
+            precision: None,
+            threshold: 1_000,
+            edge_items: 3,
+            line_width: 75,
+            suppress_small: false,
+            sign: Sign::Negative,
+        }
+    }
+
+    pub fn edge_items(mut self, edge_items: usize) -> Self {
+        self.edge_items = edge_items;
+        self
+    }
+
+    pub fn line_width(mut self, line_width: usize) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    pub fn sign(mut self, sign: Sign) -> Self {
+        self.sign = sign;
+        self
+    }
+
+    pub fn suppress_small(mut self, suppress_small: bool) -> Self {
+        self.suppress_small = suppress_small;
+        self
+    }
+
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    fn num_edge_elements(&self, array_len: usize, truncate: bool) -> usize {
+        if truncate || array_len < self.threshold {
+            usize::MAX / 2
+        } else {
+            self.edge_items
         }
     }
 }
 
-impl FormatOption {
-    fn new(array_len: usize, truncate: bool) -> Self {
-        Self::default().without_truncation(truncate || array_len < TRUNCATION_THRESHOLD)
+#[cfg(feature = "std")]
+pub fn print_options() -> PrintOptions {
+    *PRINT_OPTIONS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(not(feature = "std"))]
+pub fn print_options() -> PrintOptions {
+    PrintOptions::new()
+}
+
+#[cfg(feature = "std")]
+pub fn set_print_options(options: PrintOptions) {
+    *PRINT_OPTIONS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = options;
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn format_with_precision<T>(elem: &T, precision: Option<usize>) -> String
+where
+    T: fmt::Display + FormatElement,
+{
+    match (precision, <T as FormatElement>::kind()) {
+        (Some(precision), ElementKind::Float | ElementKind::Complex) => {
+            format!("{:.*}", precision, elem)
+        }
+        _ => format!("{}", elem),
     }
+}
 
-    fn without_truncation(mut self, valid: bool) -> Self {
-        if valid {
-            self.num_edge_elements = usize::MAX / 2;
+pub struct DisplayWith<'a, S, D, O>
+where
+    D: Dimensionality,
+{
+    array: &'a ArrayBase<S, D, O>,
+    options: &'a PrintOptions,
+}
+
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+{
+    pub fn display_with<'a>(&'a self, options: &'a PrintOptions) -> DisplayWith<'a, S, D, O> {
+        DisplayWith {
+            array: self,
+            options,
+        }
+    }
+}
+
+impl<D, O, S> fmt::Display for DisplayWith<'_, S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+    <S as Storage>::Elem: fmt::Display + FormatElement,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_array(self.array, 1, self.options, f, Style::Auto, <_>::fmt)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ElementKind {
+    Integer,
+    Float,
+    Complex,
+    Other,
+}
+
+/// Element types of arrays that can be formatted.
+///
+/// Elements are formatted with their own formatting traits, except that real
+/// floats share a notation and precision across the whole array. The provided
+/// methods format elements as they are, so other element types opt in with an
+/// empty `impl`.
+pub trait FormatElement {
+    #[doc(hidden)]
+    fn kind() -> ElementKind {
+        ElementKind::Other
+    }
+
+    #[doc(hidden)]
+    fn to_f64(&self) -> Option<f64> {
+        None
+    }
+
+    #[doc(hidden)]
+    fn write_float(&self, _: Notation, _: bool, _: &mut String) -> fmt::Result {
+        Err(fmt::Error)
+    }
+}
+
+macro_rules! impl_format_element_for_other {
+    ($( $type:ty ),*) => {
+        $(
+            impl FormatElement for $type {}
+        )*
+    };
+}
+
+impl_format_element_for_other!(bool, char, str, String);
+
+impl<T> FormatElement for *const T where T: ?Sized {}
+
+impl<T> FormatElement for *mut T where T: ?Sized {}
+
+impl<T> FormatElement for Complex<T> {
+    fn kind() -> ElementKind {
+        ElementKind::Complex
+    }
+}
+
+macro_rules! impl_format_element_by_forwarding {
+    ($( <$param:ident> $type:ty ),*) => {
+        $(
+            impl<$param> FormatElement for $type
+            where
+                $param: FormatElement + ?Sized,
+            {
+                fn kind() -> ElementKind {
+                    $param::kind()
+                }
+
+                fn to_f64(&self) -> Option<f64> {
+                    (**self).to_f64()
+                }
+
+                fn write_float(&self, notation: Notation, zero: bool, out: &mut String) -> fmt::Result {
+                    (**self).write_float(notation, zero, out)
+                }
+            }
+        )*
+    };
+}
+
+impl_format_element_by_forwarding!(<T> &T, <T> &mut T);
+
+impl<T> FormatElement for Wrapping<T>
+where
+    T: FormatElement,
+{
+    fn kind() -> ElementKind {
+        T::kind()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
+    }
+
+    fn write_float(&self, notation: Notation, zero: bool, out: &mut String) -> fmt::Result {
+        self.0.write_float(notation, zero, out)
+    }
+}
+
+macro_rules! impl_format_element_for_integer {
+    ($( $type:ty ),*) => {
+        $(
+            impl FormatElement for $type {
+                fn kind() -> ElementKind {
+                    ElementKind::Integer
+                }
+            }
+        )*
+    };
+}
+
+impl_format_element_for_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_format_element_for_float {
+    ($( $type:ty ),*) => {
+        $(
+            impl FormatElement for $type {
+                fn kind() -> ElementKind {
+                    ElementKind::Float
+                }
+
+                fn to_f64(&self) -> Option<f64> {
+                    Some(*self as f64)
+                }
+
+                fn write_float(&self, notation: Notation, zero: bool, out: &mut String) -> fmt::Result {
+                    let value = if zero { 0. } else { *self };
+                    match notation {
+                        Notation::Positional(None) => write!(out, "{}", value),
+                        Notation::Positional(Some(precision)) => write!(out, "{:.*}", precision, value),
+                        Notation::Scientific(None, false) => write!(out, "{:e}", value),
+                        Notation::Scientific(None, true) => write!(out, "{:E}", value),
+                        Notation::Scientific(Some(precision), false) => write!(out, "{:.*e}", precision, value),
+                        Notation::Scientific(Some(precision), true) => write!(out, "{:.*E}", precision, value),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_format_element_for_float!(f32, f64);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Style {
+    Auto,
+    Exponent { upper: bool },
+    Plain,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Notation {
+    Positional(Option<usize>),
+    Scientific(Option<usize>, bool),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) struct ElementFormat {
+    pub(super) alternate: bool,
+    pub(super) precision: Option<usize>,
+    pub(super) sign_plus: bool,
+    pub(super) style: Style,
+}
+
+impl ElementFormat {
+    fn new(f: &fmt::Formatter<'_>, style: Style) -> Self {
+        Self {
+            alternate: f.alternate(),
+            precision: f.precision(),
+            sign_plus: f.sign_plus(),
+            style,
+        }
+    }
+}
+
+struct Element<'a, T, F>(&'a T, &'a F);
+
+impl<T, F> fmt::Display for Element<'_, T, F>
+where
+    F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.1)(self.0, f)
+    }
+}
+
+fn format_element<T, F>(
+    elem: &T,
+    fmt: &F,
+    precision: Option<usize>,
+    alternate: bool,
+    sign_plus: bool,
+) -> Result<String, fmt::Error>
+where
+    F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    let elem = Element(elem, fmt);
+    let mut s = String::new();
+    match (precision, alternate, sign_plus) {
+        (Some(precision), false, false) => write!(s, "{:.*}", precision, elem)?,
+        (Some(precision), true, false) => write!(s, "{:#.*}", precision, elem)?,
+        (Some(precision), false, true) => write!(s, "{:+.*}", precision, elem)?,
+        (Some(precision), true, true) => write!(s, "{:+#.*}", precision, elem)?,
+        (None, false, false) => write!(s, "{}", elem)?,
+        (None, true, false) => write!(s, "{:#}", elem)?,
+        (None, false, true) => write!(s, "{:+}", elem)?,
+        (None, true, true) => write!(s, "{:+#}", elem)?,
+    }
+    Ok(s)
+}
+
+fn fraction_digits(s: &str) -> usize {
+    let mantissa = s.split(['e', 'E']).next().unwrap_or_default();
+    mantissa.find('.').map_or(0, |i| mantissa.len() - i - 1)
+}
+
+fn format_floats<T>(
+    elems: &[T],
+    options: &PrintOptions,
+    precision: Option<usize>,
+    style: Style,
+) -> Result<Vec<String>, fmt::Error>
+where
+    T: FormatElement,
+{
+    let values = elems
+        .iter()
+        .map(|elem| elem.to_f64().unwrap_or(f64::NAN))
+        .collect::<Vec<_>>();
+    let small = (0..precision.unwrap_or(8)).fold(1_f64, |small, _| small / 10.);
+    let zeros = values
+        .iter()
+        .map(|value| options.suppress_small && value.abs() < small)
+        .collect::<Vec<_>>();
+
+    let (mut min, mut max) = (f64::INFINITY, 0_f64);
+    for (value, &zero) in values.iter().zip(&zeros) {
+        let value = value.abs();
+        if !zero && value != 0. && value.is_finite() {
+            min = min.min(value);
+            max = max.max(value);
+        }
+    }
+    let scientific = match style {
+        Style::Exponent { upper } => Some(upper),
+        Style::Auto
+            if max >= 1e8 || (!options.suppress_small && (min < 1e-4 || max / min > 1e3)) =>
+        {
+            Some(false)
+        }
+        _ => None,
+    };
+
+    let mut shortest = String::new();
+    let mut max_fraction_digits = |notation: Notation| -> Result<usize, fmt::Error> {
+        let mut digits = 0;
+        for (elem, (value, &zero)) in elems.iter().zip(values.iter().zip(&zeros)) {
+            if value.is_finite() {
+                shortest.clear();
+                elem.write_float(notation, zero, &mut shortest)?;
+                digits = digits.max(fraction_digits(&shortest));
+            }
+        }
+        Ok(digits)
+    };
+    let notation = match scientific {
+        Some(upper) => Notation::Scientific(
+            match precision {
+                Some(precision) => Some(precision),
+                None => Some(max_fraction_digits(Notation::Scientific(None, upper))?),
+            },
+            upper,
+        ),
+        None => Notation::Positional(match precision {
+            Some(precision) => Some(precision),
+            None => Some(max_fraction_digits(Notation::Positional(None))?).filter(|&d| d > 0),
+        }),
+    };
+
+    let mut strings = Vec::with_capacity(elems.len());
+    for (elem, &zero) in elems.iter().zip(&zeros) {
+        let mut s = String::new();
+        elem.write_float(notation, zero, &mut s)?;
+        strings.push(s);
+    }
+    if scientific.is_some() {
+        align_exponents(&mut strings);
+    }
+    Ok(strings)
+}
+
+fn align_exponents(strings: &mut [String]) {
+    let split = |s: &str| s.find(['e', 'E']);
+    let digits = strings
+        .iter()
+        .filter_map(|s| split(s).map(|i| s[i + 1..].trim_start_matches('-').len()))
+        .max()
+        .unwrap_or(0)
+        .max(2);
+    for s in strings.iter_mut() {
+        if let Some(i) = split(s) {
+            let exponent = &s[i + 1..];
+            let (sign, exponent) = match exponent.strip_prefix('-') {
+                Some(exponent) => ('-', exponent),
+                None => ('+', exponent),
+            };
+            *s = format!("{}{}{:0>3$}", &s[..=i], sign, exponent, digits);
         }
-        self
     }
 }
 
+fn apply_sign(s: &mut String, options: &PrintOptions, sign_plus: bool) {
+    if !s.starts_with('-') && !s.starts_with('+') {
+        match options.sign {
+            Sign::Plus => s.insert(0, '+'),
+            Sign::Space => s.insert(0, ' '),
+            Sign::Negative if sign_plus => s.insert(0, '+'),
+            Sign::Negative => {}
+        }
+    }
+}
+
+fn pad(s: String, width: usize, f: &fmt::Formatter<'_>, kind: ElementKind) -> String {
+    let len = s.chars().count();
+    if len >= width {
+        return s;
+    }
+
+    let n = width - len;
+    let numeric = kind != ElementKind::Other;
+    if numeric && f.sign_aware_zero_pad() {
+        let sign = usize::from(s.starts_with(['+', '-', ' ']));
+        let prefix = if ["0x", "0X", "0b", "0o"]
+            .iter()
+            .any(|prefix| s[sign..].starts_with(prefix))
+        {
+            2
+        } else {
+            0
+        };
+        let (head, tail) = s.split_at(sign + prefix);
+        return format!("{}{}{}", head, "0".repeat(n), tail);
+    }
+
+    let (left, right) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, n),
+        Some(fmt::Alignment::Center) => (n / 2, n - n / 2),
+        Some(fmt::Alignment::Right) => (n, 0),
+        None if numeric => (n, 0),
+        None => (0, n),
+    };
+    let fill = f.fill();
+    let mut out = String::with_capacity(s.len() + n * fill.len_utf8());
+    out.extend((0..left).map(|_| fill));
+    out.push_str(&s);
+    out.extend((0..right).map(|_| fill));
+    out
+}
+
+fn visit_elements<S, D, O, F>(
+    array: &ArrayBase<S, D, O>,
+    num_edge_elements: usize,
+    visit: &mut F,
+) -> fmt::Result
+where
+    D: Dimensionality,
+    F: FnMut(&<S as Storage>::Elem) -> fmt::Result,
+    O: Order,
+    S: Storage,
+{
+    if array.ndims() == 0 {
+        return array.iter().try_for_each(visit);
+    }
+
+    let len = array.shape[0];
+    for i in 0..len {
+        if len > num_edge_elements * 2 && i >= num_edge_elements && i < len - num_edge_elements {
+            continue;
+        }
+        visit_elements(&array.slice(dyn_s!(i as isize)), num_edge_elements, visit)?;
+    }
+    Ok(())
+}
+
+pub(super) fn format_elements<S, D, O, F>(
+    array: &ArrayBase<S, D, O>,
+    options: &PrintOptions,
+    num_edge_elements: usize,
+    format: &ElementFormat,
+    fmt: &F,
+) -> Result<Vec<String>, fmt::Error>
+where
+    D: Dimensionality,
+    F: Fn(&<S as Storage>::Elem, &mut fmt::Formatter<'_>) -> fmt::Result,
+    O: Order,
+    S: Storage,
+    <S as Storage>::Elem: FormatElement,
+{
+    let kind = <<S as Storage>::Elem as FormatElement>::kind();
+    let precision = match kind {
+        ElementKind::Float | ElementKind::Complex => format.precision.or(options.precision),
+        ElementKind::Integer | ElementKind::Other => format.precision,
+    };
+
+    let mut strings = Vec::new();
+    if kind == ElementKind::Float && format.style != Style::Plain {
+        let mut elems = Vec::new();
+        visit_elements(array, num_edge_elements, &mut |elem| {
+            elems.push(elem.clone());
+            Ok(())
+        })?;
+        strings = format_floats(&elems, options, precision, format.style)?;
+    } else {
+        visit_elements(array, num_edge_elements, &mut |elem| {
+            strings.push(format_element(
+                elem,
+                fmt,
+                precision,
+                format.alternate,
+                format.sign_plus,
+            )?);
+            Ok(())
+        })?;
+    }
+    if matches!(kind, ElementKind::Float | ElementKind::Complex) {
+        for s in strings.iter_mut() {
+            apply_sign(s, options, format.sign_plus);
+        }
+    }
+    Ok(strings)
+}
+
 fn format_array<S, D, O, F>(
     array: &ArrayBase<S, D, O>,
     indent: usize,
-    option: &FormatOption,
+    options: &PrintOptions,
     f: &mut fmt::Formatter<'_>,
-    mut fmt: F,
+    style: Style,
+    fmt: F,
 ) -> fmt::Result
 where
     D: Dimensionality,
-    F: Clone + FnMut(&<S as Storage>::Elem, &mut fmt::Formatter<'_>) -> fmt::Result,
+    F: Fn(&<S as Storage>::Elem, &mut fmt::Formatter<'_>) -> fmt::Result,
     O: Order,
     S: Storage,
+    <S as Storage>::Elem: FormatElement,
 {
     if array.is_empty() {
         let n = array.ndims();
@@ -50,80 +599,110 @@ where
         return Ok(());
     }
 
-    match array.ndims() {
-        0 => {
-            for elem in array.iter() {
-                fmt(elem, f)?;
-            }
+    let num_edge_elements = options.num_edge_elements(array.len(), f.alternate());
+    let strings = format_elements(
+        array,
+        options,
+        num_edge_elements,
+        &ElementFormat::new(f, style),
+        &fmt,
+    )?;
+
+    let kind = <<S as Storage>::Elem as FormatElement>::kind();
+    let width = strings
+        .iter()
+        .map(|s| s.chars().count())
+        .chain(f.width())
+        .max()
+        .unwrap_or(0);
+    let mut strings = strings
+        .into_iter()
+        .map(|s| pad(s, width, f, kind))
+        .peekable();
+    let mut out = String::new();
+    write_nested(
+        array.shape.as_ref(),
+        indent,
+        num_edge_elements,
+        options.line_width,
+        &mut strings,
+        &mut out,
+    );
+    f.write_str(&out)
+}
+
+fn write_nested<I>(
+    shape: &[usize],
+    indent: usize,
+    num_edge_elements: usize,
+    line_width: usize,
+    strings: &mut Peekable<I>,
+    out: &mut String,
+) where
+    I: Iterator<Item = String>,
+{
+    let len = match shape.first() {
+        Some(&len) => len,
+        None => {
+            out.push_str(&strings.next().unwrap_or_default());
+            return;
         }
-        n_dims => {
-            f.write_str("[")?;
-            let len = array.shape[0];
-            if len > option.num_edge_elements * 2 {
-                for i in 0..option.num_edge_elements {
-                    fmt_indent(i, n_dims, indent, f)?;
-                    format_array(
-                        &array.slice(dyn_s!(i as isize)),
-                        indent + 1,
-                        option,
-                        f,
-                        fmt.clone(),
-                    )?;
-                }
-                fmt_indent(1, n_dims, indent, f)?;
-                f.write_str("...")?;
-                fmt_indent(1, n_dims, indent, f)?;
-                for i in 0..option.num_edge_elements {
-                    fmt_indent(i, n_dims, indent, f)?;
-                    format_array(
-                        &array.slice(dyn_s!(i as isize - option.num_edge_elements as isize)),
-                        indent + 1,
-                        option,
-                        f,
-                        fmt.clone(),
-                    )?;
-                }
+    };
+
+    out.push('[');
+    let truncated = len > num_edge_elements * 2;
+    let n_items = if truncated {
+        num_edge_elements * 2 + 1
+    } else {
+        len
+    };
+    for i in 0..n_items {
+        let is_ellipsis = truncated && i == num_edge_elements;
+        if i != 0 {
+            out.push(',');
+            if shape.len() > 1 {
+                out.push_str(&"\n".repeat(shape.len() - 1));
+                out.push_str(&" ".repeat(indent));
             } else {
-                for i in 0..len {
-                    fmt_indent(i, n_dims, indent, f)?;
-                    format_array(
-                        &array.slice(dyn_s!(i as isize)),
-                        indent + 1,
-                        option,
-                        f,
-                        fmt.clone(),
-                    )?;
+                let item_width = if is_ellipsis {
+                    3
+                } else {
+                    strings.peek().map_or(0, |s| s.chars().count())
+                };
+                let line_len = out[out.rfind('\n').map_or(0, |i| i + 1)..].chars().count();
+                if line_len + item_width + 2 > line_width {
+                    out.push('\n');
+                    out.push_str(&" ".repeat(indent));
+                } else {
+                    out.push(' ');
                 }
             }
-            f.write_str("]")?;
         }
-    }
-
-    Ok(())
-}
-
-fn fmt_indent(i: usize, n_dims: usize, indent: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    if i != 0 {
-        f.write_str(",")?;
-        if n_dims > 1 {
-            write!(f, "{}", "\n".repeat(n_dims - 1))?;
-            write!(f, "{}", " ".repeat(indent))?;
+        if is_ellipsis {
+            out.push_str("...");
         } else {
-            f.write_str(" ")?;
+            write_nested(
+                &shape[1..],
+                indent + 1,
+                num_edge_elements,
+                line_width,
+                strings,
+                out,
+            );
         }
     }
-    Ok(())
+    out.push(']');
 }
 
-impl<D, S> fmt::Binary for ArrayBase<S, D>
+impl<D, O, S> fmt::Binary for ArrayBase<S, D, O>
 where
     D: Dimensionality,
+    O: Order,
     S: Storage,
-    <S as Storage>::Elem: fmt::Binary,
+    <S as Storage>::Elem: fmt::Binary + FormatElement,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let option = FormatOption::new(self.len(), f.alternate());
-        format_array(self, 1, &option, f, <_>::fmt)?;
+        format_array(self, 1, &print_options(), f, Style::Plain, <_>::fmt)?;
         Ok(())
     }
 }
@@ -133,11 +712,10 @@ where
     D: Dimensionality,
     O: Order,
     S: Storage,
-    <S as Storage>::Elem: fmt::Debug,
+    <S as Storage>::Elem: fmt::Debug + FormatElement,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let option = FormatOption::new(self.len(), f.alternate());
-        format_array(self, 1, &option, f, <_>::fmt)?;
+        format_array(self, 1, &print_options(), f, Style::Plain, <_>::fmt)?;
         write!(
             f,
             ", shape={:?}, strides={:?}, order={}",
@@ -149,93 +727,107 @@ where
     }
 }
 
-impl<D, S> fmt::Display for ArrayBase<S, D>
+impl<D, O, S> fmt::Display for ArrayBase<S, D, O>
 where
     D: Dimensionality,
+    O: Order,
     S: Storage,
-    <S as Storage>::Elem: fmt::Display,
+    <S as Storage>::Elem: fmt::Display + FormatElement,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let option = FormatOption::new(self.len(), f.alternate());
-        format_array(self, 1, &option, f, <_>::fmt)?;
+        format_array(self, 1, &print_options(), f, Style::Auto, <_>::fmt)?;
         Ok(())
     }
 }
 
-impl<D, S> fmt::LowerExp for ArrayBase<S, D>
+impl<D, O, S> fmt::LowerExp for ArrayBase<S, D, O>
 where
     D: Dimensionality,
+    O: Order,
     S: Storage,
-    <S as Storage>::Elem: fmt::LowerExp,
+    <S as Storage>::Elem: fmt::LowerExp + FormatElement,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let option = FormatOption::new(self.len(), f.alternate());
-        format_array(self, 1, &option, f, <_>::fmt)?;
+        format_array(
+            self,
+            1,
+            &print_options(),
+            f,
+            Style::Exponent { upper: false },
+            <_>::fmt,
+        )?;
         Ok(())
     }
 }
 
-impl<D, S> fmt::LowerHex for ArrayBase<S, D>
+impl<D, O, S> fmt::LowerHex for ArrayBase<S, D, O>
 where
     D: Dimensionality,
+    O: Order,
     S: Storage,
-    <S as Storage>::Elem: fmt::LowerHex,
+    <S as Storage>::Elem: fmt::LowerHex + FormatElement,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let option = FormatOption::new(self.len(), f.alternate());
-        format_array(self, 1, &option, f, <_>::fmt)?;
+        format_array(self, 1, &print_options(), f, Style::Plain, <_>::fmt)?;
         Ok(())
     }
 }
 
-impl<D, S> fmt::Octal for ArrayBase<S, D>
+impl<D, O, S> fmt::Octal for ArrayBase<S, D, O>
 where
     D: Dimensionality,
+    O: Order,
     S: Storage,
-    <S as Storage>::Elem: fmt::Octal,
+    <S as Storage>::Elem: fmt::Octal + FormatElement,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let option = FormatOption::new(self.len(), f.alternate());
-        format_array(self, 1, &option, f, <_>::fmt)?;
+        format_array(self, 1, &print_options(), f, Style::Plain, <_>::fmt)?;
         Ok(())
     }
 }
 
-impl<D, S> fmt::Pointer for ArrayBase<S, D>
+impl<D, O, S> fmt::Pointer for ArrayBase<S, D, O>
 where
     D: Dimensionality,
+    O: Order,
     S: Storage,
-    <S as Storage>::Elem: fmt::Pointer,
+    <S as Storage>::Elem: fmt::Pointer + FormatElement,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let option = FormatOption::new(self.len(), f.alternate());
-        format_array(self, 1, &option, f, <_>::fmt)?;
+        format_array(self, 1, &print_options(), f, Style::Plain, <_>::fmt)?;
         Ok(())
     }
 }
 
-impl<D, S> fmt::UpperExp for ArrayBase<S, D>
+impl<D, O, S> fmt::UpperExp for ArrayBase<S, D, O>
 where
     D: Dimensionality,
+    O: Order,
     S: Storage,
-    <S as Storage>::Elem: fmt::UpperExp,
+    <S as Storage>::Elem: fmt::UpperExp + FormatElement,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let option = FormatOption::new(self.len(), f.alternate());
-        format_array(self, 1, &option, f, <_>::fmt)?;
+        format_array(
+            self,
+            1,
+            &print_options(),
+            f,
+            Style::Exponent { upper: true },
+            <_>::fmt,
+        )?;
         Ok(())
     }
 }
 
-impl<D, S> fmt::UpperHex for ArrayBase<S, D>
+impl<D, O, S> fmt::UpperHex for ArrayBase<S, D, O>
 where
     D: Dimensionality,
+    O: Order,
     S: Storage,
-    <S as Storage>::Elem: fmt::UpperHex,
+    <S as Storage>::Elem: fmt::UpperHex + FormatElement,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let option = FormatOption::new(self.len(), f.alternate());
-        format_array(self, 1, &option, f, <_>::fmt)?;
+        format_array(self, 1, &print_options(), f, Style::Plain, <_>::fmt)?;
         Ok(())
     }
 }
@@ -243,9 +835,12 @@ where
 #[cfg(test)]
 mod tests {
     #[cfg(not(feature = "std"))]
-    use alloc::{vec, vec::Vec};
+    use alloc::{string::String, vec, vec::Vec};
 
-    use crate::{s, ArrayBase, NDArray, NDArrayOwned, Result};
+    use core::{fmt, num::Wrapping};
+
+    use super::{FormatElement, PrintOptions, Sign};
+    use crate::{s, Array, ArrayBase, ColumnMajor, NDArray, NDArrayOwned, NDims, Result};
 
     #[test]
     fn format_empty_arrays() -> Result<()> {
@@ -270,12 +865,198 @@ mod tests {
 
     #[test]
     fn format_1d_array() {
-        const N: usize = super::TRUNCATION_THRESHOLD + 10;
+        const N: usize = 1_010;
         let data = vec![1; N];
         let a1 = ArrayBase::from(data.clone());
 
         assert_eq!(format!("{}", a1), "[1, 1, 1, ..., 1, 1, 1]");
-        assert_eq!(format!("{:#}", a1), format!("[{}]", ["1"; N].join(", ")));
+        assert_eq!(
+            format!("{:#}", a1).replace(",\n ", ", "),
+            format!("[{}]", ["1"; N].join(", "))
+        );
+        assert!(format!("{:#}", a1).lines().all(|line| line.len() <= 75));
+    }
+
+    #[test]
+    fn format_with_options() -> Result<()> {
+        let a = ArrayBase::from(vec![0.5_f64, 1.25, -0.004, 3.]).into_shape([2, 2])?;
+
+        assert_eq!(
+            format!("{}", a.display_with(&PrintOptions::new().precision(2))),
+            "[[ 0.50,  1.25],\n [-0.00,  3.00]]"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                a.display_with(&PrintOptions::new().precision(2).suppress_small(true))
+            ),
+            "[[0.50, 1.25],\n [0.00, 3.00]]"
+        );
+        assert_eq!(
+            format!("{}", a.display_with(&PrintOptions::new().sign(Sign::Plus))),
+            "[[+0.500, +1.250],\n [-0.004, +3.000]]"
+        );
+        assert_eq!(
+            format!(
+                "{:.1}",
+                a.display_with(&PrintOptions::new().precision(3).sign(Sign::Space))
+            ),
+            "[[ 0.5,  1.2],\n [-0.0,  3.0]]"
+        );
+
+        let a = ArrayBase::from(vec![1; 10]);
+        let options = PrintOptions::new().threshold(5).edge_items(1);
+
+        assert_eq!(format!("{}", a.display_with(&options)), "[1, ..., 1]");
+        assert_eq!(
+            format!("{:#}", a.display_with(&options)),
+            format!("[{}]", ["1"; 10].join(", "))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_aligned_columns() -> Result<()> {
+        let a = ArrayBase::from(vec![1_i32, 100, -10, 5]).into_shape([2, 2])?;
+
+        assert_eq!(format!("{}", a), "[[  1, 100],\n [-10,   5]]");
+        assert_eq!(format!("{:4}", a), "[[   1,  100],\n [ -10,    5]]");
+
+        let a = ArrayBase::from(vec![1_f64, 2.5, 3.25]);
+
+        assert_eq!(format!("{}", a), "[1.00, 2.50, 3.25]");
+
+        let a = ArrayBase::from(vec![0.00001_f64, 1., 1500.]);
+
+        assert_eq!(format!("{}", a), "[1.0e-05, 1.0e+00, 1.5e+03]");
+        assert_eq!(format!("{:.2}", a), "[1.00e-05, 1.00e+00, 1.50e+03]");
+        assert_eq!(
+            format!(
+                "{}",
+                a.display_with(&PrintOptions::new().suppress_small(true))
+            ),
+            "[   0.00001,    1.00000, 1500.00000]"
+        );
+        assert_eq!(
+            format!("{:e}", ArrayBase::from(vec![2_f32, 125e10])),
+            "[2.00e+00, 1.25e+12]"
+        );
+
+        let a = ArrayBase::from((0..12).collect::<Vec<i32>>());
+        let options = PrintOptions::new().line_width(20);
+
+        assert_eq!(
+            format!("{}", a.display_with(&options)),
+            "[ 0,  1,  2,  3,  4,\n  5,  6,  7,  8,  9,\n 10, 11]"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_column_major_array() -> Result<()> {
+        let a = Array::<_, NDims<2>, ColumnMajor>::from_vec_with_order::<ColumnMajor>(
+            [2, 3],
+            vec![1_u8, 4, 2, 5, 3, 6],
+        )?;
+        let b = ArrayBase::from(vec![1_u8, 2, 3, 4, 5, 6]).into_shape([2, 3])?;
+
+        assert_eq!(format!("{}", a), "[[1, 2, 3],\n [4, 5, 6]]");
+        assert_eq!(format!("{:b}", a), format!("{:b}", b));
+        assert_eq!(
+            format!("{:e}", a.transpose()),
+            format!("{:e}", b.transpose())
+        );
+        assert_eq!(format!("{:x}", a), format!("{:x}", b));
+        assert_eq!(format!("{:o}", a), format!("{:o}", b));
+        assert_eq!(format!("{:X}", a), format!("{:X}", b));
+        assert_eq!(format!("{:E}", a), format!("{:E}", b));
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_with_formatter_flags() -> Result<()> {
+        let a = ArrayBase::from(vec![1_i32, -20]);
+
+        assert_eq!(format!("{:05}", a), "[00001, -0020]");
+        assert_eq!(format!("{:<4}", a), "[1   , -20 ]");
+        assert_eq!(format!("{:*^5}", a), "[**1**, *-20*]");
+        assert_eq!(format!("{:+}", a), "[ +1, -20]");
+        assert_eq!(
+            format!("{:#06x}", ArrayBase::from(vec![1_u8, 255])),
+            "[0x0001, 0x00ff]"
+        );
+
+        let b = ArrayBase::from(vec![String::from("ab"), String::from("1.5")]);
+
+        assert_eq!(format!("{}", b), "[ab , 1.5]");
+        assert_eq!(format!("{:>4}", b), "[  ab,  1.5]");
+        assert_eq!(
+            format!("{}", b.display_with(&PrintOptions::new().precision(1))),
+            "[ab , 1.5]"
+        );
+
+        let c = ArrayBase::from(vec![1e-10_f64, 2.5]);
+
+        assert_eq!(
+            format!(
+                "{}",
+                c.display_with(&PrintOptions::new().precision(3).suppress_small(true))
+            ),
+            "[0.000, 2.500]"
+        );
+        assert_eq!(format!("{:08.2}", c.slice(s![1..])), "[00002.50]");
+
+        Ok(())
+    }
+
+    #[test]
+    fn format_notation_by_element_type() {
+        assert_eq!(
+            format!("{}", ArrayBase::from(vec![1_u64, 1_000_000_000_000])),
+            "[            1, 1000000000000]"
+        );
+        assert_eq!(
+            format!("{}", ArrayBase::from(vec![1_f64, 1e12])),
+            "[1e+00, 1e+12]"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ArrayBase::from(vec![String::from("1e12"), String::from("1")])
+            ),
+            "[1e12, 1   ]"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                ArrayBase::from(vec![Wrapping(1.5_f64), Wrapping(1e12)])
+            ),
+            "[1.5e+00, 1.0e+12]"
+        );
+
+        #[derive(Clone)]
+        struct Label(&'static str);
+
+        impl fmt::Display for Label {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.0)
+            }
+        }
+
+        impl FormatElement for Label {}
+
+        assert_eq!(
+            format!("{}", ArrayBase::from(vec![Label("a"), Label("bc")])),
+            "[a , bc]"
+        );
+    }
+
+    #[test]
+    fn default_print_options() {
+        assert_eq!(super::print_options(), PrintOptions::default());
     }
 
     #[test]
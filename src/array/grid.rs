@@ -0,0 +1,147 @@
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    storage::Storage, Array, ArrayIndex, DynDim, NDArray, NDArrayOwned, NDims, RowMajor, Shape,
+    SliceInfo,
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Indexing {
+    Xy,
+    Ij,
+}
+
+fn grid_axis(i: usize, n_arrays: usize, indexing: Indexing) -> usize {
+    match indexing {
+        Indexing::Xy if n_arrays >= 2 && i < 2 => 1 - i,
+        _ => i,
+    }
+}
+
+pub fn indices<Sh>(shape: &Sh) -> Array<usize, DynDim>
+where
+    Sh: Shape,
+{
+    if shape.ndims() == 0 {
+        return Array::zeros(&vec![0]);
+    }
+
+    let ranges = shape
+        .as_ref()
+        .iter()
+        .map(|&dim| (0..dim).collect::<Array<_, NDims<1>>>())
+        .collect::<Vec<_>>();
+    Array::stack(&meshgrid(&ranges, Indexing::Ij), 0).unwrap()
+}
+
+pub fn meshgrid<T>(
+    arrays: &[T],
+    indexing: Indexing,
+) -> Vec<Array<<T::Storage as Storage>::Elem, DynDim, T::Order>>
+where
+    T: NDArray<Dimensionality = NDims<1>>,
+{
+    let n_arrays = arrays.len();
+    let mut shape = vec![0; n_arrays];
+    for (i, array) in arrays.iter().enumerate() {
+        shape[grid_axis(i, n_arrays, indexing)] = array.len();
+    }
+
+    meshgrid_sparse(arrays, indexing)
+        .iter()
+        .map(|sparse| {
+            let dense = sparse.broadcast_to::<DynDim>(&shape).unwrap();
+            Array::from_vec_with_order::<RowMajor>(shape.clone(), dense.iter().cloned().collect())
+                .unwrap()
+        })
+        .collect()
+}
+
+pub fn meshgrid_sparse<T>(arrays: &[T], indexing: Indexing) -> Vec<T::ViewWithD<'_, DynDim>>
+where
+    T: NDArray<Dimensionality = NDims<1>>,
+{
+    let n_arrays = arrays.len();
+    arrays
+        .iter()
+        .enumerate()
+        .map(|(i, array)| {
+            let axis = grid_axis(i, n_arrays, indexing);
+            array.slice(SliceInfo::from(
+                (0..n_arrays)
+                    .map(|j| {
+                        if j == axis {
+                            ArrayIndex::from(..)
+                        } else {
+                            ArrayIndex::NewAxis
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use super::{indices, meshgrid, meshgrid_sparse, Indexing};
+    use crate::{array, NDArray};
+
+    #[test]
+    fn dense_meshgrid() {
+        let x = array!([1, 2, 3]);
+        let y = array!([4, 5]);
+
+        let xy = meshgrid(&[x.view(), y.view()], Indexing::Xy);
+
+        assert_eq!(xy[0].shape(), &vec![2, 3]);
+        assert_eq!(
+            xy[0].iter().cloned().collect::<Vec<_>>(),
+            vec![1, 2, 3, 1, 2, 3]
+        );
+        assert_eq!(
+            xy[1].iter().cloned().collect::<Vec<_>>(),
+            vec![4, 4, 4, 5, 5, 5]
+        );
+
+        let ij = meshgrid(&[x, y], Indexing::Ij);
+
+        assert_eq!(ij[0].shape(), &vec![3, 2]);
+        assert_eq!(
+            ij[0].iter().cloned().collect::<Vec<_>>(),
+            vec![1, 1, 2, 2, 3, 3]
+        );
+        assert_eq!(
+            ij[1].iter().cloned().collect::<Vec<_>>(),
+            vec![4, 5, 4, 5, 4, 5]
+        );
+    }
+
+    #[test]
+    fn sparse_meshgrid() {
+        let arrays = [array!([1, 2, 3]), array!([4, 5]), array!([6])];
+        let grids = meshgrid_sparse(&arrays, Indexing::Xy);
+
+        assert_eq!(grids[0].shape(), &vec![1, 3, 1]);
+        assert_eq!(grids[1].shape(), &vec![2, 1, 1]);
+        assert_eq!(grids[2].shape(), &vec![1, 1, 1]);
+        assert_eq!(grids[0].as_ptr(), arrays[0].as_ptr());
+        assert_eq!(grids[1].iter().cloned().collect::<Vec<_>>(), vec![4, 5]);
+    }
+
+    #[test]
+    fn index_grid() {
+        let grid = indices(&[2, 3]);
+
+        assert_eq!(grid.shape(), &vec![2, 2, 3]);
+        assert_eq!(
+            grid.iter().cloned().collect::<Vec<_>>(),
+            vec![0, 0, 0, 1, 1, 1, 0, 1, 2, 0, 1, 2]
+        );
+        assert_eq!(indices(&[]).shape(), &vec![0]);
+    }
+}
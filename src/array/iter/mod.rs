@@ -162,7 +162,7 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     #[cfg(not(feature = "std"))]
     use alloc::{vec, vec::Vec};
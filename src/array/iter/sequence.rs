@@ -157,7 +157,7 @@ impl<'a, T> ElementIterator<'a, T> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     #[cfg(not(feature = "std"))]
     use alloc::vec::Vec;
@@ -4,9 +4,86 @@ use num_traits::Zero;
 
 use crate::{
     array::iter::SequenceIter, storage::Storage, ArrayBase, Dimensionality, DimensionalityAfterDot,
-    Dot, NDArray, NDArrayMut, NDArrayOwned, Order, Shape,
+    Dot, NDArray, NDArrayMut, NDArrayOwned, Order, Result, Shape, ShapeError,
 };
 
+fn dot_into<'a, 'b, D, D1, O, O2, S, S1, T>(
+    lhs: &ArrayBase<S, D, O>,
+    rhs: T,
+) -> Result<ArrayBase<<S as Storage>::Owned, <D as DimensionalityAfterDot<D1>>::Output, O2>>
+where
+    D: Dimensionality + DimensionalityAfterDot<D1>,
+    <<D as DimensionalityAfterDot<D1>>::Output as Dimensionality>::Shape:
+        Shape<Dimensionality = <D as DimensionalityAfterDot<D1>>::Output>,
+    D1: Dimensionality,
+    O: Order,
+    O2: Order,
+    S: Storage,
+    <S as Storage>::Elem: AddAssign<<S as Storage>::Elem> + Zero + 'a,
+    &'a <S as Storage>::Elem: Mul<&'b <S1 as Storage>::Elem, Output = <S as Storage>::Elem>,
+    S1: Storage,
+    <S1 as Storage>::Elem: 'b,
+    T: NDArray<Dimensionality = D1, Storage = S1>,
+{
+    let in_n_dims = lhs.ndims();
+    let rhs_n_dims = rhs.ndims();
+
+    if in_n_dims == 0 || rhs_n_dims == 0 {
+        return Err(ShapeError::IncompatibleDimension(
+            "dot products for 0-dimensional arrays are not supported".into(),
+        )
+        .into());
+    }
+
+    let match_axis = if rhs_n_dims > 1 { rhs_n_dims - 2 } else { 0 };
+    if lhs.shape()[in_n_dims - 1] != rhs.shape()[match_axis] {
+        return Err(ShapeError::IncompatibleShape(message!(
+            "shapes {:?} and {:?} not aligned: {} (dim {}) != {} (dim {})",
+            lhs.shape(),
+            rhs.shape(),
+            lhs.shape()[in_n_dims - 1],
+            in_n_dims - 1,
+            rhs.shape()[match_axis],
+            match_axis
+        ))
+        .into());
+    }
+
+    let out_n_dims = in_n_dims + rhs_n_dims - 2;
+    let mut out_shape = <D as DimensionalityAfterDot<D1>>::Output::shape_zeroed(out_n_dims);
+    for (out_dim, dim) in out_shape.as_mut().iter_mut().zip(
+        lhs.shape()
+            .as_ref()
+            .iter()
+            .take(in_n_dims - 1)
+            .chain(rhs.shape().as_ref().iter().take(match_axis)),
+    ) {
+        *out_dim = *dim
+    }
+    if rhs_n_dims > 1 {
+        out_shape[out_n_dims - 1] = rhs.shape()[rhs_n_dims - 1];
+    }
+
+    let mut out = ArrayBase::<<S as Storage>::Owned, _, O2>::try_zeros(&out_shape)?;
+    let mut out_iter = out.iter_mut();
+    let in_iters = SequenceIter::new(lhs, in_n_dims - 1);
+    for in_iter in in_iters {
+        let rhs_iters = SequenceIter::new(&rhs, match_axis);
+        for rhs_iter in rhs_iters {
+            if let Some(out_elem) = out_iter.next() {
+                let mut it = in_iter.clone().zip(rhs_iter);
+                if let Some((in_elem, rhs_elem)) = it.next() {
+                    *out_elem = in_elem * rhs_elem;
+                    for (in_elem, rhs_elem) in it {
+                        *out_elem += in_elem * rhs_elem;
+                    }
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
 macro_rules! impl_dot {
     ($type:ty) => {
         impl<'a, 'b, D, D1, O, S, S1, T> Dot<T> for $type
@@ -21,66 +98,13 @@ macro_rules! impl_dot {
             &'a <S as Storage>::Elem: Mul<&'b <S1 as Storage>::Elem, Output = <S as Storage>::Elem>,
             S1: Storage,
             <S1 as Storage>::Elem: 'b,
-            T: NDArray<Dimensionality = D1, Order = O, Storage = S1>,
+            T: NDArray<Dimensionality = D1, Storage = S1>,
         {
             type Output =
                 ArrayBase<<S as Storage>::Owned, <D as DimensionalityAfterDot<D1>>::Output, O>;
 
             fn dot(&self, rhs: T) -> Self::Output {
-                let in_n_dims = self.ndims();
-                let rhs_n_dims = rhs.ndims();
-
-                if in_n_dims == 0 || rhs_n_dims == 0 {
-                    panic!("dot products for 0-dimensional arrays are not supported");
-                }
-
-                let match_axis = if rhs_n_dims > 1 { rhs_n_dims - 2 } else { 0 };
-                if self.shape()[in_n_dims - 1] != rhs.shape()[match_axis] {
-                    panic!(
-                        "shapes {:?} and {:?} not aligned: {} (dim {}) != {} (dim {})",
-                        self.shape(),
-                        rhs.shape(),
-                        self.shape()[in_n_dims - 1],
-                        in_n_dims - 1,
-                        rhs.shape()[match_axis],
-                        match_axis
-                    );
-                }
-
-                let out_n_dims = in_n_dims + rhs_n_dims - 2;
-                let mut out_shape =
-                    <D as DimensionalityAfterDot<D1>>::Output::shape_zeroed(out_n_dims);
-                for (out_dim, dim) in out_shape.as_mut().iter_mut().zip(
-                    self.shape()
-                        .as_ref()
-                        .iter()
-                        .take(in_n_dims - 1)
-                        .chain(rhs.shape().as_ref().iter().take(match_axis)),
-                ) {
-                    *out_dim = *dim
-                }
-                if rhs_n_dims > 1 {
-                    out_shape[out_n_dims - 1] = rhs.shape()[rhs_n_dims - 1];
-                }
-
-                let mut out = Self::Output::zeros(&out_shape);
-                let mut out_iter = out.iter_mut();
-                let in_iters = SequenceIter::new(self, in_n_dims - 1);
-                for in_iter in in_iters {
-                    let rhs_iters = SequenceIter::new(&rhs, match_axis);
-                    for rhs_iter in rhs_iters {
-                        if let Some(out_elem) = out_iter.next() {
-                            let mut it = in_iter.clone().zip(rhs_iter);
-                            if let Some((in_elem, rhs_elem)) = it.next() {
-                                *out_elem = in_elem * rhs_elem;
-                                for (in_elem, rhs_elem) in it {
-                                    *out_elem += in_elem * rhs_elem;
-                                }
-                            }
-                        }
-                    }
-                }
-                out
+                dot_into::<D, D1, O, O, S, S1, T>(self, rhs).unwrap()
             }
         }
     };
@@ -90,12 +114,52 @@ impl_dot!(ArrayBase<S, D, O>);
 impl_dot!(&'a ArrayBase<S, D, O>);
 impl_dot!(&'a mut ArrayBase<S, D, O>);
 
-#[cfg(test)]
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+{
+    /// Dot product laid out in the order `O2`.
+    ///
+    /// [`Dot::dot`] always keeps the order of the left operand; use this method
+    /// to choose a different one. Returns an error instead of panicking when
+    /// the shapes are not aligned or the result does not fit in the storage.
+    #[allow(clippy::type_complexity)]
+    pub fn dot_with_order<'a, 'b, O2, T>(
+        &self,
+        rhs: T,
+    ) -> Result<
+        ArrayBase<
+            <S as Storage>::Owned,
+            <D as DimensionalityAfterDot<T::Dimensionality>>::Output,
+            O2,
+        >,
+    >
+    where
+        D: DimensionalityAfterDot<T::Dimensionality>,
+        <<D as DimensionalityAfterDot<T::Dimensionality>>::Output as Dimensionality>::Shape:
+            Shape<Dimensionality = <D as DimensionalityAfterDot<T::Dimensionality>>::Output>,
+        O2: Order,
+        <S as Storage>::Elem: AddAssign<<S as Storage>::Elem> + Zero + 'a,
+        &'a <S as Storage>::Elem:
+            Mul<&'b <T::Storage as Storage>::Elem, Output = <S as Storage>::Elem>,
+        <T::Storage as Storage>::Elem: 'b,
+        T: NDArray,
+    {
+        dot_into::<D, T::Dimensionality, O, O2, S, T::Storage, T>(self, rhs)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     #[cfg(not(feature = "std"))]
     use alloc::{vec, vec::Vec};
 
-    use crate::{array, s, storage::StorageBase, ArrayBase, Dot, NDArray, NDArrayOwned};
+    use crate::{
+        array, s, storage::StorageBase, Array, ArrayBase, ColumnMajor, Dot, NDArray, NDArrayOwned,
+        NDims, RowMajor,
+    };
 
     #[test]
     fn dot_1d() {
@@ -163,4 +227,33 @@ mod tests {
 
         assert_eq!(a.dot(&b), array!([[0, 0], [0, 0]]));
     }
+
+    #[test]
+    fn dot_mixed_order() {
+        let a = array!([[1, 2, 3], [4, 5, 6]]);
+        let b = Array::<_, NDims<2>, ColumnMajor>::from_shape_vec([3, 2], vec![1, 3, 5, 2, 4, 6])
+            .unwrap();
+        let expected = ArrayBase::from(vec![22, 28, 49, 64])
+            .into_shape([2, 2])
+            .unwrap();
+
+        assert_eq!(a.dot(&b), expected);
+
+        let c = b.dot(&a);
+
+        assert_eq!(c.strides(), &[1, 3]);
+        assert_eq!(
+            c.iter().cloned().collect::<Vec<_>>(),
+            vec![9, 12, 15, 19, 26, 33, 29, 40, 51]
+        );
+        assert_eq!(
+            a.dot_with_order::<ColumnMajor, _>(&b).unwrap().strides(),
+            &[1, 2]
+        );
+        assert_eq!(
+            b.dot_with_order::<RowMajor, _>(&a).unwrap().strides(),
+            &[3, 1]
+        );
+        assert!(a.dot_with_order::<RowMajor, _>(&a).is_err());
+    }
 }
@@ -0,0 +1,156 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::ArrayBase;
+use crate::{
+    routine,
+    storage::{Storage, StorageMut, StorageOwned},
+    Array, Dimensionality, DimensionalityMax, NDArray, NDArrayMut, Order, Result, RowMajor,
+};
+
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+{
+    pub fn map<F, U>(&self, f: F) -> Array<U, D, O>
+    where
+        F: FnMut(&<S as Storage>::Elem) -> U,
+        U: Clone,
+    {
+        Array::from_vec_with_order::<RowMajor>(self.shape.clone(), self.iter().map(f).collect())
+            .unwrap()
+    }
+
+    pub fn map_inplace<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut <S as Storage>::Elem),
+        S: StorageMut,
+    {
+        for elem in self.iter_mut() {
+            f(elem);
+        }
+    }
+
+    pub fn mapv<F, U>(&self, mut f: F) -> Array<U, D, O>
+    where
+        F: FnMut(<S as Storage>::Elem) -> U,
+        U: Clone,
+    {
+        self.map(|elem| f(elem.clone()))
+    }
+
+    pub fn mapv_into<F>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(<S as Storage>::Elem) -> <S as Storage>::Elem,
+        S: StorageMut + StorageOwned,
+    {
+        self.map_inplace(|elem| *elem = f(elem.clone()));
+        self
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn zip_with<F, T, U>(
+        &self,
+        other: &T,
+        mut f: F,
+    ) -> Result<Array<U, <D as DimensionalityMax<T::Dimensionality>>::Output, O>>
+    where
+        D: DimensionalityMax<T::Dimensionality>,
+        F: FnMut(&<S as Storage>::Elem, &<T::Storage as Storage>::Elem) -> U,
+        T: NDArray,
+        U: Clone,
+    {
+        let out_shape =
+            routine::broadcast_shape::<D, T::Dimensionality>(&self.shape, other.shape())?;
+        let lhs =
+            self.broadcast_to::<<D as DimensionalityMax<T::Dimensionality>>::Output>(&out_shape)?;
+        let rhs = other
+            .broadcast_to::<<D as DimensionalityMax<T::Dimensionality>>::Output>(&out_shape)?;
+        let data = lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(l, r)| f(l, r))
+            .collect::<Vec<_>>();
+        Array::from_vec_with_order::<RowMajor>(out_shape, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use crate::{array, s, Array, ColumnMajor, NDArray, NDArrayMut, NDArrayOwned, NDims, Result};
+
+    #[test]
+    fn map() -> Result<()> {
+        let a = array!([[1_i32, 2], [3, 4]]);
+        let b = a.map(|&x| x as f64 / 2.);
+
+        assert_eq!(b.shape(), &[2, 2]);
+        assert_eq!(
+            b.iter().cloned().collect::<Vec<_>>(),
+            vec![0.5, 1., 1.5, 2.]
+        );
+        assert_eq!(
+            a.slice(s![.., 1])
+                .mapv(|x| x > 2)
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![false, true]
+        );
+
+        let c = Array::<_, NDims<2>, ColumnMajor>::from_shape_vec([2, 2], vec![1, 2, 3, 4])?;
+        let d = c.mapv(|x| x * 10);
+
+        assert_eq!(d.iter().cloned().collect::<Vec<_>>(), vec![10, 30, 20, 40]);
+        assert_eq!(d.strides(), c.strides());
+
+        Ok(())
+    }
+
+    #[test]
+    fn map_inplace() {
+        let mut a = array!([1, 2, 3]);
+        let ptr = a.as_ptr();
+        a.map_inplace(|x| *x += 1);
+        let a = a.mapv_into(|x| x * x);
+
+        assert_eq!(a.iter().cloned().collect::<Vec<_>>(), vec![4, 9, 16]);
+        assert_eq!(a.as_ptr(), ptr);
+
+        let mut b = array!([[1, 2], [3, 4]]);
+        b.slice_mut(s![.., 0]).map_inplace(|x| *x = 0);
+
+        assert_eq!(b.iter().cloned().collect::<Vec<_>>(), vec![0, 2, 0, 4]);
+    }
+
+    #[test]
+    fn zip_with() -> Result<()> {
+        let a = array!([[1, 2, 3], [4, 5, 6]]);
+        let b = array!([10, 20, 30]);
+        let c = a.zip_with(&b, |&x, &y| (x + y) as f32)?;
+
+        assert_eq!(c.shape(), &[2, 3]);
+        assert_eq!(
+            c.iter().cloned().collect::<Vec<_>>(),
+            vec![11., 22., 33., 14., 25., 36.]
+        );
+
+        let column = array!([[1], [2]]);
+
+        assert_eq!(
+            b.zip_with(&column, |x, y| x * y)?
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![10, 20, 30, 20, 40, 60]
+        );
+        assert!(a.zip_with(&array!([1, 2]), |x, y| x + y).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,322 @@
+use num_complex::Complex;
+use num_traits::Float;
+
+use super::ArrayBase;
+use crate::{
+    storage::{Storage, StorageMut},
+    Array, Dimensionality, NDArray, Order, RowMajor,
+};
+
+pub trait ElementMath: Clone {
+    type Real: Float;
+    fn abs(self) -> Self::Real;
+    fn acos(self) -> Self;
+    fn acosh(self) -> Self;
+    fn asin(self) -> Self;
+    fn asinh(self) -> Self;
+    fn atan(self) -> Self;
+    fn atanh(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn cos(self) -> Self;
+    fn cosh(self) -> Self;
+    fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn ln(self) -> Self;
+    fn log10(self) -> Self;
+    fn log2(self) -> Self;
+    fn powf(self, n: Self::Real) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn sin(self) -> Self;
+    fn sinh(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn tan(self) -> Self;
+    fn tanh(self) -> Self;
+}
+
+macro_rules! impl_element_math {
+    ($type:ty, $real:ty, $path:ident, $abs:expr, $powi:expr) => {
+        impl ElementMath for $type {
+            type Real = $real;
+
+            fn abs(self) -> Self::Real {
+                $abs(self)
+            }
+
+            impl_element_math!(
+                @forward $path; acos, acosh, asin, asinh, atan, atanh, cbrt, cos, cosh, exp, exp2, ln,
+                log10, log2, sin, sinh, sqrt, tan, tanh
+            );
+
+            fn powf(self, n: Self::Real) -> Self {
+                $path::powf(self, n)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                $powi(self, n)
+            }
+        }
+    };
+    (@forward $path:ident; $( $name:ident ),*) => {
+        $(
+            fn $name(self) -> Self {
+                $path::$name(self)
+            }
+        )*
+    };
+}
+
+impl_element_math!(f32, f32, Float, Float::abs, Float::powi);
+impl_element_math!(f64, f64, Float, Float::abs, Float::powi);
+impl_element_math!(
+    Complex<f32>,
+    f32,
+    Complex,
+    Complex::norm,
+    |x: Complex<f32>, n| { Complex::powi(&x, n) }
+);
+impl_element_math!(
+    Complex<f64>,
+    f64,
+    Complex,
+    Complex::norm,
+    |x: Complex<f64>, n| { Complex::powi(&x, n) }
+);
+
+macro_rules! impl_unary_functions {
+    ($bound:ident, $( ($name:ident, $name_inplace:ident) ),* $(,)?) => {
+        $(
+            pub fn $name(&self) -> ArrayBase<<S as Storage>::Owned, D, O> {
+                self.map_elements(|x| $bound::$name(x))
+            }
+
+            pub fn $name_inplace(&mut self)
+            where
+                S: StorageMut,
+            {
+                self.map_inplace(|x| *x = $bound::$name(x.clone()))
+            }
+        )*
+    };
+}
+
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+    <S as Storage>::Elem: ElementMath,
+{
+    impl_unary_functions!(
+        ElementMath,
+        (acos, acos_inplace),
+        (acosh, acosh_inplace),
+        (asin, asin_inplace),
+        (asinh, asinh_inplace),
+        (atan, atan_inplace),
+        (atanh, atanh_inplace),
+        (cbrt, cbrt_inplace),
+        (cos, cos_inplace),
+        (cosh, cosh_inplace),
+        (exp, exp_inplace),
+        (exp2, exp2_inplace),
+        (ln, ln_inplace),
+        (log10, log10_inplace),
+        (log2, log2_inplace),
+        (sin, sin_inplace),
+        (sinh, sinh_inplace),
+        (sqrt, sqrt_inplace),
+        (tan, tan_inplace),
+        (tanh, tanh_inplace),
+    );
+
+    pub fn abs(&self) -> Array<<<S as Storage>::Elem as ElementMath>::Real, D, O> {
+        Array::from_vec_with_order::<RowMajor>(
+            self.shape.clone(),
+            self.iter().cloned().map(ElementMath::abs).collect(),
+        )
+        .unwrap()
+    }
+
+    pub fn powf(
+        &self,
+        n: <<S as Storage>::Elem as ElementMath>::Real,
+    ) -> ArrayBase<<S as Storage>::Owned, D, O> {
+        self.map_elements(|x| x.powf(n))
+    }
+
+    pub fn powf_inplace(&mut self, n: <<S as Storage>::Elem as ElementMath>::Real)
+    where
+        S: StorageMut,
+    {
+        self.map_inplace(|x| *x = x.clone().powf(n))
+    }
+
+    pub fn powi(&self, n: i32) -> ArrayBase<<S as Storage>::Owned, D, O> {
+        self.map_elements(|x| x.powi(n))
+    }
+
+    pub fn powi_inplace(&mut self, n: i32)
+    where
+        S: StorageMut,
+    {
+        self.map_inplace(|x| *x = x.clone().powi(n))
+    }
+}
+
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+    <S as Storage>::Elem: Float,
+{
+    impl_unary_functions!(
+        Float,
+        (ceil, ceil_inplace),
+        (floor, floor_inplace),
+        (round, round_inplace),
+        (signum, signum_inplace),
+        (trunc, trunc_inplace),
+    );
+
+    pub fn abs_inplace(&mut self)
+    where
+        S: StorageMut,
+    {
+        self.map_inplace(|x| *x = Float::abs(*x))
+    }
+
+    pub fn clamp(
+        &self,
+        min: <S as Storage>::Elem,
+        max: <S as Storage>::Elem,
+    ) -> ArrayBase<<S as Storage>::Owned, D, O> {
+        self.map_elements(|x| x.max(min).min(max))
+    }
+
+    pub fn clamp_inplace(&mut self, min: <S as Storage>::Elem, max: <S as Storage>::Elem)
+    where
+        S: StorageMut,
+    {
+        self.map_inplace(|x| *x = x.max(min).min(max))
+    }
+}
+
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+{
+    fn map_elements<F>(&self, f: F) -> ArrayBase<<S as Storage>::Owned, D, O>
+    where
+        F: FnMut(<S as Storage>::Elem) -> <S as Storage>::Elem,
+    {
+        ArrayBase::from_vec_with_order::<RowMajor>(
+            self.shape.clone(),
+            self.iter().cloned().map(f).collect(),
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use num_complex::Complex;
+
+    use crate::{array, s, Array, ColumnMajor, NDArray, NDArrayMut, NDArrayOwned, NDims, Result};
+
+    #[test]
+    fn float_functions() -> Result<()> {
+        let a = array!([[0_f64, 1.], [4., 9.]]);
+
+        assert_eq!(
+            a.sqrt().iter().cloned().collect::<Vec<_>>(),
+            vec![0., 1., 2., 3.]
+        );
+        assert_eq!(a.exp().ln().iter().cloned().collect::<Vec<_>>()[2], 4.);
+        assert_eq!(
+            a.powi(2).iter().cloned().collect::<Vec<_>>(),
+            vec![0., 1., 16., 81.]
+        );
+        assert_eq!(a.powf(0.5), a.sqrt());
+
+        let b = Array::from(vec![-1.5_f32, -0.2, 0.7, 2.5]).into_shape([2, 2])?;
+
+        assert_eq!(
+            b.abs().iter().cloned().collect::<Vec<_>>(),
+            vec![1.5, 0.2, 0.7, 2.5]
+        );
+        assert_eq!(
+            b.floor().iter().cloned().collect::<Vec<_>>(),
+            vec![-2., -1., 0., 2.]
+        );
+        assert_eq!(
+            b.clamp(-1., 1.).iter().cloned().collect::<Vec<_>>(),
+            vec![-1., -0.2, 0.7, 1.]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn inplace_functions() -> Result<()> {
+        let mut a = array!([1_f64, 2., 3.]);
+        a.powi_inplace(3);
+        a.cbrt_inplace();
+
+        for (x, y) in a.iter().zip([1., 2., 3.]) {
+            assert!((x - y).abs() < 1e-12);
+        }
+
+        let mut b = array!([1.2_f64, 2.5, 3.7]);
+        b.slice_mut(s![1..]).ceil_inplace();
+
+        assert_eq!(b.iter().cloned().collect::<Vec<_>>(), vec![1.2, 3., 4.]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn column_major_functions() -> Result<()> {
+        let c =
+            Array::<_, NDims<2>, ColumnMajor>::from_shape_vec([2, 2], vec![1_f32, 4., 9., 16.])?;
+        let sqrt = c.sqrt();
+
+        assert_eq!(
+            sqrt.iter().cloned().collect::<Vec<_>>(),
+            vec![1., 3., 2., 4.]
+        );
+        assert_eq!(sqrt.strides(), c.strides());
+
+        Ok(())
+    }
+
+    #[test]
+    fn complex_functions() -> Result<()> {
+        let a = Array::from(vec![
+            Complex::new(0_f64, core::f64::consts::PI),
+            Complex::new(-4., 0.),
+        ])
+        .into_shape([2])?;
+        let exp = a.exp();
+        let sqrt = a.sqrt();
+
+        assert!((exp.iter().next().unwrap() - Complex::new(-1., 0.)).norm() < 1e-12);
+        assert!((sqrt.iter().nth(1).unwrap() - Complex::new(0., 2.)).norm() < 1e-12);
+        assert_eq!(a.powi(2).iter().nth(1), Some(&Complex::new(16., 0.)));
+        assert_eq!(
+            Array::from(vec![Complex::new(3_f32, -4.)])
+                .abs()
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![5.]
+        );
+
+        Ok(())
+    }
+}
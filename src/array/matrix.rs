@@ -0,0 +1,147 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::Mul;
+
+use num_traits::{One, Zero};
+
+use super::ArrayBase;
+use crate::{
+    storage::{Storage, StorageBase},
+    NDArray, NDArrayOwned, NDims, Order,
+};
+
+impl<T, O> ArrayBase<StorageBase<Vec<T>>, NDims<2>, O>
+where
+    T: Clone,
+    O: Order,
+{
+    pub fn eye(n: usize, m: usize, k: isize) -> Self
+    where
+        T: One + Zero,
+    {
+        Self::from_shape_fn(&[n, m], |&[i, j]| {
+            if j as isize - i as isize == k {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    pub fn from_diag<A>(diag: &A, k: isize) -> Self
+    where
+        A: NDArray<Dimensionality = NDims<1>>,
+        A::Storage: Storage<Elem = T>,
+        T: Zero,
+    {
+        let diag = diag.iter().cloned().collect::<Vec<_>>();
+        let n = diag.len() + k.unsigned_abs();
+        Self::from_shape_fn(&[n, n], |&[i, j]| {
+            if j as isize - i as isize == k {
+                diag[i.min(j)].clone()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    pub fn identity(n: usize) -> Self
+    where
+        T: One + Zero,
+    {
+        Self::eye(n, n, 0)
+    }
+
+    pub fn tri(n: usize, m: usize, k: isize) -> Self
+    where
+        T: One + Zero,
+    {
+        Self::from_shape_fn(&[n, m], |&[i, j]| {
+            if j as isize - i as isize <= k {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    pub fn vander<A>(x: &A, n: usize) -> Self
+    where
+        A: NDArray<Dimensionality = NDims<1>>,
+        A::Storage: Storage<Elem = T>,
+        T: Mul<Output = T> + One,
+    {
+        let x = x.iter().cloned().collect::<Vec<_>>();
+        Self::from_shape_fn(&[x.len(), n], |&[i, j]| {
+            num_traits::pow(x[i].clone(), n - 1 - j)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use crate::{array, Array, ColumnMajor, NDArray, NDims, Order};
+
+    fn to_vec<T: Clone, O: Order>(a: &Array<T, NDims<2>, O>) -> Vec<T> {
+        a.iter().cloned().collect()
+    }
+
+    #[test]
+    fn eye_and_identity() {
+        assert_eq!(
+            to_vec(&Array::<i32, _>::eye(2, 3, 1)),
+            vec![0, 1, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            to_vec(&Array::<i32, _>::eye(3, 2, -1)),
+            vec![0, 0, 1, 0, 0, 1]
+        );
+
+        let a = Array::<f64, _, ColumnMajor>::identity(2);
+
+        assert_eq!(a.shape(), &[2, 2]);
+        assert_eq!(a.strides(), &[1, 2]);
+        assert_eq!(to_vec(&a), vec![1., 0., 0., 1.]);
+    }
+
+    #[test]
+    fn from_diag() {
+        let v = array!([1, 2]);
+
+        assert_eq!(to_vec(&Array::<_, _>::from_diag(&v, 0)), vec![1, 0, 0, 2]);
+        assert_eq!(
+            to_vec(&Array::<_, _>::from_diag(&v, 1)),
+            vec![0, 1, 0, 0, 0, 2, 0, 0, 0]
+        );
+        assert_eq!(
+            to_vec(&Array::<_, _>::from_diag(&v, -1)),
+            vec![0, 0, 0, 1, 0, 0, 0, 2, 0]
+        );
+    }
+
+    #[test]
+    fn tri() {
+        assert_eq!(
+            to_vec(&Array::<u8, _>::tri(3, 3, 0)),
+            vec![1, 0, 0, 1, 1, 0, 1, 1, 1]
+        );
+        assert_eq!(
+            to_vec(&Array::<u8, _>::tri(2, 3, -1)),
+            vec![0, 0, 0, 1, 0, 0]
+        );
+    }
+
+    #[test]
+    fn vander() {
+        let x = array!([1, 2, 3]);
+
+        assert_eq!(
+            to_vec(&Array::<_, _>::vander(&x, 3)),
+            vec![1, 1, 1, 4, 2, 1, 9, 3, 1]
+        );
+        assert_eq!(Array::<_, _>::vander(&x, 0).shape(), &[3, 0]);
+    }
+}
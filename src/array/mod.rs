@@ -1,25 +1,78 @@
+#[cfg(feature = "alloc")]
+mod cast;
+
+#[cfg(feature = "alloc")]
 mod fmt;
+#[cfg(feature = "std")]
+pub(crate) use fmt::format_with_precision;
+#[cfg(feature = "std")]
+pub use fmt::set_print_options;
+#[cfg(feature = "alloc")]
+pub use fmt::{print_options, DisplayWith, FormatElement, PrintOptions, Sign};
+
+#[cfg(feature = "alloc")]
+mod grid;
+#[cfg(feature = "alloc")]
+pub use grid::{indices, meshgrid, meshgrid_sparse, Indexing};
 
 mod iter;
 pub use iter::{Iter, IterMut};
 
 mod linarg;
 
+#[cfg(feature = "alloc")]
+mod map;
+
+#[cfg(feature = "alloc")]
+mod matrix;
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+mod math;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use math::ElementMath;
+
 mod ops;
 
-mod routine;
+#[cfg(feature = "alloc")]
+mod promote;
+#[cfg(feature = "alloc")]
+pub use promote::Promote;
+
+#[cfg(feature = "alloc")]
+mod range;
+#[cfg(feature = "alloc")]
+pub use range::RangeElement;
+
+pub(crate) mod routine;
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", feature = "serde"))]
+mod serde;
+
+#[cfg(feature = "alloc")]
+mod table;
+#[cfg(feature = "alloc")]
+pub use table::{LatexStyle, TableOptions};
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
-use core::{iter::FromIterator, marker::PhantomData, mem};
+#[cfg(feature = "alloc")]
+use core::iter::FromIterator;
+use core::{marker::PhantomData, mem};
 
 use num_traits::{One, Zero};
 
+#[cfg(feature = "mmap")]
+use crate::storage::{Mmap, MmapMut};
+#[cfg(feature = "alloc")]
+use crate::{
+    storage::{AlignedVec, StorageBase},
+    Error,
+};
 use crate::{
-    storage::{Storage, StorageBase, StorageMut, StorageOwned},
-    util, ArrayIndex, DimDiff, Dimensionality, DimensionalityAdd, DimensionalityDiff, Error,
-    NDArray, NDArrayMut, NDArrayOwned, NDims, Order, Result, RowMajor, Shape, ShapeError,
-    SignedShape, SliceInfo,
+    storage::{InlineStorage, Storage, StorageMut, StorageOwned},
+    util, ArrayIndex, DimDiff, Dimensionality, DimensionalityAdd, DimensionalityDiff, NDArray,
+    NDArrayMut, NDArrayOwned, NDims, Order, Result, RowMajor, Shape, ShapeError, SignedShape,
+    SliceInfo,
 };
 
 #[derive(Clone, Hash, Eq, PartialEq)]
@@ -34,20 +87,27 @@ where
     phantom: PhantomData<O>,
 }
 
+#[cfg(feature = "alloc")]
 pub type Array<T, D, O = RowMajor> = ArrayBase<StorageBase<Vec<T>>, D, O>;
 
+#[cfg(feature = "alloc")]
+pub type AlignedArray<T, D, const ALIGN: usize, O = RowMajor> =
+    ArrayBase<StorageBase<AlignedVec<T, ALIGN>>, D, O>;
+
+#[cfg(feature = "alloc")]
 impl<T> From<Vec<T>> for ArrayBase<StorageBase<Vec<T>>, NDims<1>> {
     fn from(data: Vec<T>) -> Self {
         Self {
             shape: [data.len()],
             strides: [1],
-            storage: StorageBase::from(data),
+            storage: StorageBase::<Vec<_>>::from(data),
             offset: 0,
             phantom: PhantomData,
         }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> FromIterator<T> for ArrayBase<StorageBase<Vec<T>>, NDims<1>>
 where
     T: Clone,
@@ -64,6 +124,63 @@ where
     }
 }
 
+impl<T, const N: usize> From<[T; N]> for ArrayBase<InlineStorage<T, N>, NDims<1>> {
+    fn from(data: [T; N]) -> Self {
+        Self {
+            shape: [N],
+            strides: [1],
+            storage: InlineStorage::from(data),
+            offset: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<T> From<Mmap<T>> for ArrayBase<StorageBase<Mmap<T>>, NDims<1>>
+where
+    T: Copy,
+{
+    fn from(data: Mmap<T>) -> Self {
+        let storage = StorageBase::from(data);
+        Self {
+            shape: [storage.as_slice().len()],
+            strides: [1],
+            storage,
+            offset: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<T> From<MmapMut<T>> for ArrayBase<StorageBase<MmapMut<T>>, NDims<1>>
+where
+    T: Copy,
+{
+    fn from(data: MmapMut<T>) -> Self {
+        let storage = StorageBase::from(data);
+        Self {
+            shape: [storage.as_slice().len()],
+            strides: [1],
+            storage,
+            offset: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<D, O, T> ArrayBase<StorageBase<MmapMut<T>>, D, O>
+where
+    D: Dimensionality,
+    T: Copy,
+{
+    pub fn flush(&self) -> Result<()> {
+        self.storage.flush()
+    }
+}
+
 #[rustfmt::skip]
 macro_rules! impl_ndarray {
     ($type:ty) => {
@@ -237,7 +354,7 @@ macro_rules! impl_ndarray {
                 let mut counts = <D as Dimensionality>::shape_zeroed(axes.ndims());
                 for &axis in axes.as_ref() {
                     if axis >= axes.ndims() {
-                        return Err(ShapeError::IncompatibleAxis(format!(
+                        return Err(ShapeError::IncompatibleAxis(message!(
                             "axis {} is out of bounds for array of dimension {}",
                             axis,
                             self.ndims()
@@ -475,6 +592,7 @@ where
         }
     }
 
+    #[cfg(feature = "alloc")]
     fn concatenate<T>(arrays: &[T], axis: isize) -> Result<Self>
     where
         Self: Sized,
@@ -516,7 +634,7 @@ where
                     if i == axis_normalized {
                         *dim += *d;
                     } else if dim != d {
-                        return Err(ShapeError::IncompatibleDimension(format!(
+                        return Err(ShapeError::IncompatibleDimension(message!(
                             "{} {}",
                             "all the input array dimensions except for the",
                             "concatenation axis must match exactly"
@@ -527,6 +645,7 @@ where
             }
         }
 
+        Self::check_capacity(shape.as_associated_shape())?;
         let mut out = Self::allocate_uninitialized(&shape);
         let mut slice_idx = 0_isize;
         for array in arrays {
@@ -552,6 +671,76 @@ where
         Ok(out)
     }
 
+    #[cfg(feature = "alloc")]
+    fn from_shape_fn<Sh, F>(shape: &Sh, mut f: F) -> Self
+    where
+        F: FnMut(
+            &<Self::Dimensionality as Dimensionality>::Shape,
+        ) -> <Self::Storage as Storage>::Elem,
+        Sh: Shape<Dimensionality = Self::Dimensionality>,
+    {
+        let shape = shape.as_associated_shape().clone();
+        let len = shape.array_len();
+        let mut index = shape.clone();
+        index.as_mut().iter_mut().for_each(|i| *i = 0);
+        let mut data = Vec::with_capacity(len);
+        for _ in 0..len {
+            data.push(f(&index));
+            for (i, &dim) in index.as_mut().iter_mut().zip(shape.as_ref()).rev() {
+                *i += 1;
+                if *i < dim {
+                    break;
+                }
+                *i = 0;
+            }
+        }
+        Self::from_vec_with_order::<RowMajor>(shape, data).unwrap()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn from_shape_vec<Sh>(shape: Sh, data: Vec<<Self::Storage as Storage>::Elem>) -> Result<Self>
+    where
+        Sh: Shape<Dimensionality = Self::Dimensionality>,
+    {
+        Self::from_shape_vec_with_order::<_, O>(shape, data)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn from_shape_vec_with_order<Sh, O2>(
+        shape: Sh,
+        data: Vec<<Self::Storage as Storage>::Elem>,
+    ) -> Result<Self>
+    where
+        Sh: Shape<Dimensionality = Self::Dimensionality>,
+        O2: Order,
+    {
+        Self::from_vec_with_order::<O2>(shape.as_associated_shape().clone(), data)
+    }
+
+    fn full<Sh>(shape: &Sh, value: <Self::Storage as Storage>::Elem) -> Self
+    where
+        Sh: Shape<Dimensionality = Self::Dimensionality>,
+    {
+        ArrayBase {
+            shape: shape.as_associated_shape().clone(),
+            strides: shape.as_associated_shape().to_default_strides::<O>(),
+            storage: (0..shape.as_associated_shape().array_len())
+                .map(|_| value.clone())
+                .collect(),
+            offset: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    fn full_like<A>(array: &A, value: <Self::Storage as Storage>::Elem) -> Self
+    where
+        A: NDArray,
+        <<A as NDArray>::Dimensionality as Dimensionality>::Shape:
+            Shape<Dimensionality = Self::Dimensionality>,
+    {
+        Self::full(array.shape(), value)
+    }
+
     fn into_shape<Sh>(self, shape: Sh) -> Result<Self::WithD<<Sh as SignedShape>::Dimensionality>>
     where
         Sh: SignedShape,
@@ -620,6 +809,17 @@ where
         }
     }
 
+    fn ones_like<A>(array: &A) -> Self
+    where
+        <Self::Storage as Storage>::Elem: One,
+        A: NDArray,
+        <<A as NDArray>::Dimensionality as Dimensionality>::Shape:
+            Shape<Dimensionality = Self::Dimensionality>,
+    {
+        Self::ones(array.shape())
+    }
+
+    #[cfg(feature = "alloc")]
     fn stack<T>(arrays: &[T], axis: isize) -> Result<Self>
     where
         Self: Sized,
@@ -637,6 +837,32 @@ where
         Self::WithD::concatenate(&expanded_arrays, axis)
     }
 
+    fn try_full<Sh>(shape: &Sh, value: <Self::Storage as Storage>::Elem) -> Result<Self>
+    where
+        Sh: Shape<Dimensionality = Self::Dimensionality>,
+    {
+        Self::check_capacity(shape.as_associated_shape())?;
+        Ok(Self::full(shape, value))
+    }
+
+    fn try_ones<Sh>(shape: &Sh) -> Result<Self>
+    where
+        <Self::Storage as Storage>::Elem: One,
+        Sh: Shape<Dimensionality = Self::Dimensionality>,
+    {
+        Self::check_capacity(shape.as_associated_shape())?;
+        Ok(Self::ones(shape))
+    }
+
+    fn try_zeros<Sh>(shape: &Sh) -> Result<Self>
+    where
+        <Self::Storage as Storage>::Elem: Zero,
+        Sh: Shape<Dimensionality = Self::Dimensionality>,
+    {
+        Self::check_capacity(shape.as_associated_shape())?;
+        Ok(Self::zeros(shape))
+    }
+
     fn zeros<Sh>(shape: &Sh) -> Self
     where
         <Self::Storage as Storage>::Elem: Zero,
@@ -650,6 +876,84 @@ where
             phantom: PhantomData,
         }
     }
+
+    fn zeros_like<A>(array: &A) -> Self
+    where
+        <Self::Storage as Storage>::Elem: Zero,
+        A: NDArray,
+        <<A as NDArray>::Dimensionality as Dimensionality>::Shape:
+            Shape<Dimensionality = Self::Dimensionality>,
+    {
+        Self::zeros(array.shape())
+    }
+}
+
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: StorageOwned,
+{
+    pub(crate) fn check_capacity(shape: &<D as Dimensionality>::Shape) -> Result<()> {
+        if shape.array_len() > S::CAPACITY {
+            return Err(ShapeError::IncompatibleShape(message!(
+                "cannot hold array of shape {:?} in storage for {} elements",
+                shape,
+                S::CAPACITY
+            ))
+            .into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: StorageMut + StorageOwned,
+{
+    pub(crate) fn from_vec_with_order<O2>(
+        shape: <D as Dimensionality>::Shape,
+        data: Vec<<S as Storage>::Elem>,
+    ) -> Result<Self>
+    where
+        O2: Order,
+    {
+        if shape.array_len() != data.len() {
+            return Err(ShapeError::IncompatibleShape(message!(
+                "cannot create array of shape {:?} from {} elements",
+                shape,
+                data.len()
+            ))
+            .into());
+        }
+        Self::check_capacity(&shape)?;
+
+        if util::type_eq::<O, O2>() {
+            return Self::from_shape_storage(shape, data.into_iter().collect());
+        }
+
+        let src = ArrayBase::<_, D, O2> {
+            strides: shape.to_default_strides::<O2>(),
+            shape: shape.clone(),
+            storage: StorageBase::<Vec<_>>::from(data),
+            offset: 0,
+            phantom: PhantomData,
+        };
+        let mut out = ArrayBase {
+            strides: shape.to_default_strides::<O>(),
+            storage: S::allocate_uninitialized(shape.array_len()),
+            shape,
+            offset: 0,
+            phantom: PhantomData,
+        };
+        for (dst, src) in out.iter_mut().zip(src.iter()) {
+            *dst = src.clone();
+        }
+        Ok(out)
+    }
 }
 
 impl<D, O, S> ArrayBase<S, D, O>
@@ -658,6 +962,29 @@ where
     O: Order,
     S: Storage,
 {
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_shape_storage(
+        shape: <D as Dimensionality>::Shape,
+        storage: S,
+    ) -> Result<Self> {
+        if shape.array_len() != storage.as_slice().len() {
+            return Err(ShapeError::IncompatibleShape(message!(
+                "cannot create array of shape {:?} from {} elements",
+                shape,
+                storage.as_slice().len()
+            ))
+            .into());
+        }
+
+        Ok(ArrayBase {
+            strides: shape.to_default_strides::<O>(),
+            shape,
+            storage,
+            offset: 0,
+            phantom: PhantomData,
+        })
+    }
+
     fn convert_shape<Sh>(
         &self,
         shape: &Sh,
@@ -888,7 +1215,7 @@ where
                     .chain(shape.as_ref().iter().skip(i + 1))
                     .product::<isize>();
                 if rest_dim == 0 {
-                    return Err(ShapeError::IncompatibleShape(format!(
+                    return Err(ShapeError::IncompatibleShape(message!(
                         "cannot transform array of length {} into shape {:?}",
                         self.len(),
                         shape
@@ -901,7 +1228,7 @@ where
             }
         }
         if inferred.array_len() != self.len() {
-            return Err(ShapeError::IncompatibleShape(format!(
+            return Err(ShapeError::IncompatibleShape(message!(
                 "cannot transform array of length {} into shape {:?}",
                 self.len(),
                 shape
@@ -957,7 +1284,7 @@ macro_rules! array {
     }}
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     #[cfg(not(feature = "std"))]
     use alloc::vec::Vec;
@@ -1400,6 +1727,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn from_shape_fn() {
+        let a = Array::<_, _>::from_shape_fn(&[2, 3], |&[i, j]| 10 * i + j);
+
+        assert_eq!(
+            a.iter().cloned().collect::<Vec<_>>(),
+            vec![0, 1, 2, 10, 11, 12]
+        );
+
+        let b = Array::<_, _, ColumnMajor>::from_shape_fn(&vec![2, 3], |idx| 10 * idx[0] + idx[1]);
+
+        assert_eq!(b.shape(), &vec![2, 3]);
+        assert_eq!(b.storage.as_slice(), &[0, 10, 1, 11, 2, 12]);
+    }
+
+    #[test]
+    fn from_shape_vec() -> Result<()> {
+        let a = Array::<_, NDims<2>>::from_shape_vec([2, 2], vec![1, 2, 3, 4])?;
+
+        assert_eq!(a.storage.as_slice(), &[1, 2, 3, 4]);
+        assert!(Array::<_, NDims<2>>::from_shape_vec([2, 3], vec![1, 2, 3, 4]).is_err());
+
+        let b = Array::<_, NDims<2>, ColumnMajor>::from_shape_vec_with_order::<_, RowMajor>(
+            [2, 2],
+            vec![1, 2, 3, 4],
+        )?;
+
+        assert_eq!(b.storage.as_slice(), &[1, 3, 2, 4]);
+        assert_eq!(b.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn full() {
+        let shape = [2, 3];
+        let a = Array::<_, _>::full(&shape, 7_u8);
+
+        assert_eq!(a.shape(), &shape);
+        assert!(a.iter().all(|&x| x == 7));
+
+        let b = Array::<_, _>::full_like(&a.transpose(), 1.5_f32);
+
+        assert_eq!(b.shape(), &[3, 2]);
+        assert!(b.iter().all(|&x| x == 1.5));
+        assert!(Array::<f64, _>::zeros_like(&b).iter().all(|&x| x == 0.));
+        assert!(Array::<i32, _, ColumnMajor>::ones_like(&b)
+            .iter()
+            .all(|&x| x == 1));
+    }
+
     #[test]
     fn is_empty() -> Result<()> {
         let a1 = Array::from(Vec::<usize>::new());
@@ -1,3 +1,5 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
 use core::{
     marker::PhantomData,
     num::Wrapping,
@@ -11,10 +13,12 @@ use core::{
 use num_complex::Complex;
 
 use super::ArrayBase;
+#[cfg(feature = "alloc")]
+use crate::storage::StorageBase;
 use crate::{
     routine,
     storage::{Storage, StorageMut, StorageOwned},
-    Dimensionality, DimensionalityMax, NDArray, NDArrayMut, NDArrayOwned, Order, Shape,
+    Dimensionality, DimensionalityMax, NDArray, NDArrayMut, NDArrayOwned, Order, Result, Shape,
 };
 
 macro_rules! impl_unary_op {
@@ -76,6 +80,8 @@ where
     out_strides
 }
 
+// Operands may have different orders; the result takes the order of the left
+// operand unless it is chosen explicitly through `*_with_order`
 macro_rules! impl_binary_op {
     ($trait:ident, $op:ident) => {
         impl<'a, D, D1, O, S, S1, T> $trait<T> for ArrayBase<S, D, O>
@@ -89,7 +95,7 @@ macro_rules! impl_binary_op {
             <S as Storage>::Elem: $trait<&'a <S1 as Storage>::Elem, Output = <S as Storage>::Elem>,
             S1: Storage,
             <S1 as Storage>::Elem: 'a,
-            T: NDArray<Dimensionality = D1, Order = O, Storage = S1>,
+            T: NDArray<Dimensionality = D1, Storage = S1>,
         {
             type Output = ArrayBase<S, <D as DimensionalityMax<D1>>::Output, O>;
 
@@ -124,8 +130,14 @@ macro_rules! impl_binary_op {
                         phantom: PhantomData,
                     }
                 } else {
+                    let lhs = self
+                        .broadcast_to::<<D as DimensionalityMax<D1>>::Output>(&out_shape)
+                        .unwrap();
+                    let rhs = rhs
+                        .broadcast_to::<<D as DimensionalityMax<D1>>::Output>(&out_shape)
+                        .unwrap();
                     let mut out = Self::Output::allocate_uninitialized(&out_shape);
-                    for (dst, (l, r)) in out.iter_mut().zip(self.iter().zip(rhs.iter())) {
+                    for (dst, (l, r)) in out.iter_mut().zip(lhs.iter().zip(rhs.iter())) {
                         *dst = l.clone().$op(r);
                     }
                     out
@@ -146,15 +158,21 @@ macro_rules! impl_binary_op {
                 $trait<&'b <S1 as Storage>::Elem, Output = <S as Storage>::Elem>,
             S1: Storage,
             <S1 as Storage>::Elem: 'b,
-            T: NDArray<Dimensionality = D1, Order = O, Storage = S1>,
+            T: NDArray<Dimensionality = D1, Storage = S1>,
         {
             type Output = ArrayBase<<S as Storage>::Owned, <D as DimensionalityMax<D1>>::Output, O>;
 
             fn $op(self, rhs: T) -> Self::Output {
                 let out_shape =
                     routine::broadcast_shape::<D, D1>(&self.shape, rhs.shape()).unwrap();
+                let lhs = self
+                    .broadcast_to::<<D as DimensionalityMax<D1>>::Output>(&out_shape)
+                    .unwrap();
+                let rhs = rhs
+                    .broadcast_to::<<D as DimensionalityMax<D1>>::Output>(&out_shape)
+                    .unwrap();
                 let mut out = Self::Output::allocate_uninitialized(&out_shape);
-                for (dst, (l, r)) in out.iter_mut().zip(self.iter().zip(rhs.iter())) {
+                for (dst, (l, r)) in out.iter_mut().zip(lhs.iter().zip(rhs.iter())) {
                     *dst = l.$op(r);
                 }
                 out
@@ -174,6 +192,75 @@ impl_binary_op!(Shl, shl);
 impl_binary_op!(Shr, shr);
 impl_binary_op!(Sub, sub);
 
+macro_rules! impl_binary_op_with_order {
+    ($( ($name:ident, $trait:ident, $op:ident) ),* $(,)?) => {
+        $(
+            #[doc = concat!(
+                "Applies [`", stringify!($trait), "`] elementwise after broadcasting both operands, ",
+                "laying the result out in the order `O2`.\n\n",
+                "The operator form always keeps the order of the left operand; use this method to ",
+                "choose a different one. Returns an error if the shapes cannot be broadcast together ",
+                "or the result does not fit in the storage."
+            )]
+            #[allow(clippy::type_complexity)]
+            pub fn $name<O2, T>(
+                &self,
+                rhs: T,
+            ) -> Result<ArrayBase<<S as Storage>::Owned, <D as DimensionalityMax<T::Dimensionality>>::Output, O2>>
+            where
+                D: DimensionalityMax<T::Dimensionality>,
+                <<D as DimensionalityMax<T::Dimensionality>>::Output as Dimensionality>::Shape:
+                    Shape<Dimensionality = <D as DimensionalityMax<T::Dimensionality>>::Output>,
+                O2: Order,
+                <S as Storage>::Elem:
+                    $trait<<T::Storage as Storage>::Elem, Output = <S as Storage>::Elem>,
+                T: NDArray,
+            {
+                let out_shape =
+                    routine::broadcast_shape::<D, T::Dimensionality>(&self.shape, rhs.shape())?;
+                let lhs = self
+                    .broadcast_to::<<D as DimensionalityMax<T::Dimensionality>>::Output>(&out_shape)?;
+                let rhs = rhs
+                    .broadcast_to::<<D as DimensionalityMax<T::Dimensionality>>::Output>(&out_shape)?;
+                ArrayBase::<
+                    <S as Storage>::Owned,
+                    <D as DimensionalityMax<T::Dimensionality>>::Output,
+                    O2,
+                >::check_capacity(&out_shape)?;
+                let mut out = ArrayBase::<
+                    <S as Storage>::Owned,
+                    <D as DimensionalityMax<T::Dimensionality>>::Output,
+                    O2,
+                >::allocate_uninitialized(&out_shape);
+                for (dst, (l, r)) in out.iter_mut().zip(lhs.iter().zip(rhs.iter())) {
+                    *dst = l.clone().$op(r.clone());
+                }
+                Ok(out)
+            }
+        )*
+    };
+}
+
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+{
+    impl_binary_op_with_order!(
+        (add_with_order, Add, add),
+        (bitand_with_order, BitAnd, bitand),
+        (bitor_with_order, BitOr, bitor),
+        (bitxor_with_order, BitXor, bitxor),
+        (div_with_order, Div, div),
+        (mul_with_order, Mul, mul),
+        (rem_with_order, Rem, rem),
+        (shl_with_order, Shl, shl),
+        (shr_with_order, Shr, shr),
+        (sub_with_order, Sub, sub),
+    );
+}
+
 macro_rules! impl_binary_op_with_type {
     (<$( $param:ident ),*>, $trait:ident, $op:ident, $type:ty) => {
         impl<D, O, S, $( $param ),*> $trait<$type> for ArrayBase<S, D, O>
@@ -214,6 +301,41 @@ macro_rules! impl_binary_op_with_type {
                 out
             }
         }
+
+        #[cfg(feature = "alloc")]
+        impl<'a, D, E, O, $( $param ),*> $trait<$type> for ArrayBase<StorageBase<&'a [E]>, D, O>
+        where
+            D: Dimensionality,
+            <D as Dimensionality>::Shape: Shape<Dimensionality = D>,
+            E: Clone,
+            for<'b> &'b E: $trait<$type, Output = E>,
+            O: Order,
+            $( $param: Copy ),*
+        {
+            type Output = ArrayBase<<StorageBase<&'a [E]> as Storage>::Owned, D, O>;
+
+            fn $op(self, rhs: $type) -> Self::Output {
+                $trait::$op(&self, rhs)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<'a, D, E, O, $( $param ),*> $trait<$type>
+            for ArrayBase<StorageBase<&'a mut [E]>, D, O>
+        where
+            D: Dimensionality,
+            <D as Dimensionality>::Shape: Shape<Dimensionality = D>,
+            E: Clone,
+            for<'b> &'b E: $trait<$type, Output = E>,
+            O: Order,
+            $( $param: Copy ),*
+        {
+            type Output = ArrayBase<<StorageBase<&'a mut [E]> as Storage>::Owned, D, O>;
+
+            fn $op(self, rhs: $type) -> Self::Output {
+                $trait::$op(&self, rhs)
+            }
+        }
     };
 }
 
@@ -238,14 +360,12 @@ impl_all_binary_op_with_type!(<>, u8);
 impl_all_binary_op_with_type!(<>, u16);
 impl_all_binary_op_with_type!(<>, u32);
 impl_all_binary_op_with_type!(<>, u64);
-#[cfg(has_i128)]
 impl_all_binary_op_with_type!(<>, u128);
 impl_all_binary_op_with_type!(<>, isize);
 impl_all_binary_op_with_type!(<>, i8);
 impl_all_binary_op_with_type!(<>, i16);
 impl_all_binary_op_with_type!(<>, i32);
 impl_all_binary_op_with_type!(<>, i64);
-#[cfg(has_i128)]
 impl_all_binary_op_with_type!(<>, i128);
 impl_all_binary_op_with_type!(<>, f32);
 impl_all_binary_op_with_type!(<>, f64);
@@ -287,6 +407,34 @@ macro_rules! impl_binary_op_for_type {
                 out
             }
         }
+
+        #[cfg(feature = "alloc")]
+        impl<'a, D, O> $trait<ArrayBase<StorageBase<&'a [$type]>, D, O>> for $type
+        where
+            D: Dimensionality,
+            <D as Dimensionality>::Shape: Shape<Dimensionality = D>,
+            O: Order,
+        {
+            type Output = ArrayBase<StorageBase<Vec<$type>>, D, O>;
+
+            fn $op(self, rhs: ArrayBase<StorageBase<&'a [$type]>, D, O>) -> Self::Output {
+                <Self as $trait<&ArrayBase<StorageBase<&'a [$type]>, D, O>>>::$op(self, &rhs)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<'a, D, O> $trait<ArrayBase<StorageBase<&'a mut [$type]>, D, O>> for $type
+        where
+            D: Dimensionality,
+            <D as Dimensionality>::Shape: Shape<Dimensionality = D>,
+            O: Order,
+        {
+            type Output = ArrayBase<StorageBase<Vec<$type>>, D, O>;
+
+            fn $op(self, rhs: ArrayBase<StorageBase<&'a mut [$type]>, D, O>) -> Self::Output {
+                <Self as $trait<&ArrayBase<StorageBase<&'a mut [$type]>, D, O>>>::$op(self, &rhs)
+            }
+        }
     };
 }
 
@@ -314,14 +462,12 @@ impl_all_binary_op_for_type!(u8);
 impl_all_binary_op_for_type!(u16);
 impl_all_binary_op_for_type!(u32);
 impl_all_binary_op_for_type!(u64);
-#[cfg(has_i128)]
 impl_all_binary_op_for_type!(u128);
 impl_all_binary_op_for_type!(isize);
 impl_all_binary_op_for_type!(i8);
 impl_all_binary_op_for_type!(i16);
 impl_all_binary_op_for_type!(i32);
 impl_all_binary_op_for_type!(i64);
-#[cfg(has_i128)]
 impl_all_binary_op_for_type!(i128);
 
 impl_binary_op_for_type!(Add, add, f32);
@@ -375,6 +521,40 @@ macro_rules! impl_binary_op_for_wrapper {
                 out
             }
         }
+
+        #[cfg(feature = "alloc")]
+        impl<'a, D, E, O, T> $trait<ArrayBase<StorageBase<&'a [E]>, D, O>> for $wrapper<T>
+        where
+            Self: $trait<E, Output = E>,
+            D: Dimensionality,
+            <D as Dimensionality>::Shape: Shape<Dimensionality = D>,
+            E: Clone,
+            O: Order,
+            T: Copy,
+        {
+            type Output = ArrayBase<StorageBase<Vec<E>>, D, O>;
+
+            fn $op(self, rhs: ArrayBase<StorageBase<&'a [E]>, D, O>) -> Self::Output {
+                <Self as $trait<&ArrayBase<StorageBase<&'a [E]>, D, O>>>::$op(self, &rhs)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<'a, D, E, O, T> $trait<ArrayBase<StorageBase<&'a mut [E]>, D, O>> for $wrapper<T>
+        where
+            Self: $trait<E, Output = E>,
+            D: Dimensionality,
+            <D as Dimensionality>::Shape: Shape<Dimensionality = D>,
+            E: Clone,
+            O: Order,
+            T: Copy,
+        {
+            type Output = ArrayBase<StorageBase<Vec<E>>, D, O>;
+
+            fn $op(self, rhs: ArrayBase<StorageBase<&'a mut [E]>, D, O>) -> Self::Output {
+                <Self as $trait<&ArrayBase<StorageBase<&'a mut [E]>, D, O>>>::$op(self, &rhs)
+            }
+        }
     };
 }
 
@@ -407,7 +587,7 @@ macro_rules! impl_binary_assign_op {
             <S as Storage>::Elem: $trait<&'a <S1 as Storage>::Elem>,
             S1: Storage,
             <S1 as Storage>::Elem: 'a,
-            T: NDArray<Dimensionality = D1, Order = O, Storage = S1>,
+            T: NDArray<Dimensionality = D1, Storage = S1>,
         {
             fn $op(&mut self, rhs: T) {
                 if self.shape.as_ref() == rhs.shape().as_ref() {
@@ -489,26 +669,30 @@ impl_all_binary_assign_op_with_type!(<>, u8);
 impl_all_binary_assign_op_with_type!(<>, u16);
 impl_all_binary_assign_op_with_type!(<>, u32);
 impl_all_binary_assign_op_with_type!(<>, u64);
-#[cfg(has_i128)]
 impl_all_binary_assign_op_with_type!(<>, u128);
 impl_all_binary_assign_op_with_type!(<>, isize);
 impl_all_binary_assign_op_with_type!(<>, i8);
 impl_all_binary_assign_op_with_type!(<>, i16);
 impl_all_binary_assign_op_with_type!(<>, i32);
 impl_all_binary_assign_op_with_type!(<>, i64);
-#[cfg(has_i128)]
 impl_all_binary_assign_op_with_type!(<>, i128);
 impl_all_binary_assign_op_with_type!(<>, f32);
 impl_all_binary_assign_op_with_type!(<>, f64);
 impl_all_binary_assign_op_with_type!(<T>, Complex<T>);
 impl_all_binary_assign_op_with_type!(<T>, Wrapping<T>);
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     #[cfg(not(feature = "std"))]
-    use alloc::vec;
+    use alloc::{vec, vec::Vec};
+    use core::num::Wrapping;
 
-    use crate::{ArrayBase, NDArray, NDArrayOwned};
+    use num_complex::Complex;
+
+    use crate::{
+        array, s, Array, ArrayBase, ColumnMajor, NDArray, NDArrayMut, NDArrayOwned, NDims, Result,
+        RowMajor,
+    };
 
     #[test]
     fn unary_ops() {
@@ -631,6 +815,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn broadcast_both_operands() {
+        let a = array!([[10], [20]]);
+        let b = array!([1, 2, 3]);
+        let expected = vec![11, 12, 13, 21, 22, 23];
+
+        let c = &a + &b;
+
+        assert_eq!(c.shape(), &[2, 3]);
+        assert_eq!(c.iter().cloned().collect::<Vec<_>>(), expected);
+
+        let d = a + &b;
+
+        assert_eq!(d.shape(), &[2, 3]);
+        assert_eq!(d.iter().cloned().collect::<Vec<_>>(), expected);
+    }
+
     #[test]
     fn binary_assign_ops() {
         let a3_ = (10_usize..)
@@ -668,4 +869,143 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn scalar_ops() {
+        let mut a = array!([[1_f64, 2.], [4., 8.]]);
+
+        assert_eq!(
+            (1. / &a).iter().cloned().collect::<Vec<_>>(),
+            vec![1., 0.5, 0.25, 0.125]
+        );
+        assert_eq!(
+            (2. * a.view()).iter().cloned().collect::<Vec<_>>(),
+            vec![2., 4., 8., 16.]
+        );
+        assert_eq!(
+            (a.slice(s![.., 1]) - 1.)
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![1., 7.]
+        );
+        assert_eq!(
+            (10. - a.slice_mut(s![1, ..]))
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![6., 2.]
+        );
+        assert_eq!(
+            (1_f32 - array!([0.5_f32, 2.]))
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![0.5, -1.]
+        );
+
+        let b = array!([1_u8, 2, 4]);
+
+        assert_eq!(
+            (1_u8 << b.view()).iter().cloned().collect::<Vec<_>>(),
+            vec![2, 4, 16]
+        );
+        assert_eq!(
+            (true ^ array!([true, false]).view())
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![false, true]
+        );
+
+        let e = Array::from(vec![1_i128 << 100, -3]);
+
+        assert_eq!(
+            (2_i128 * &e).iter().cloned().collect::<Vec<_>>(),
+            vec![1 << 101, -6]
+        );
+        assert_eq!(
+            (&array!([1_u128, 2]) + 1_u128)
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+
+        let c = Array::from(vec![Complex::new(1_f64, 1.), Complex::new(0., 2.)]);
+
+        assert_eq!(
+            (Complex::new(0., 1.) * c.view())
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![Complex::new(-1., 1.), Complex::new(-2., 0.)]
+        );
+
+        let d = Array::from(vec![Wrapping(250_u8), Wrapping(3)]);
+
+        assert_eq!(
+            (Wrapping(10_u8) + &d).iter().cloned().collect::<Vec<_>>(),
+            vec![Wrapping(4), Wrapping(13)]
+        );
+        assert_eq!(
+            (Wrapping(0_u8) - d.view())
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![Wrapping(6), Wrapping(253)]
+        );
+    }
+
+    #[test]
+    fn mixed_order_ops() -> Result<()> {
+        let a = array!([[1, 2, 3], [4, 5, 6]]);
+        let b = Array::<_, NDims<2>, ColumnMajor>::from_shape_vec([2, 3], vec![1, 4, 2, 5, 3, 6])?;
+
+        let c = &a + &b;
+
+        assert_eq!(
+            c.iter().cloned().collect::<Vec<_>>(),
+            vec![2, 4, 6, 8, 10, 12]
+        );
+        assert_eq!(c.strides(), a.strides());
+
+        let d = &b - a.clone();
+
+        assert!(d.iter().all(|&x| x == 0));
+        assert_eq!(d.strides(), b.strides());
+
+        let e = a.mul_with_order::<ColumnMajor, _>(&b)?;
+
+        assert_eq!(
+            e.iter().cloned().collect::<Vec<_>>(),
+            vec![1, 4, 9, 16, 25, 36]
+        );
+        assert_eq!(e.strides(), b.strides());
+
+        let mut f = b.clone();
+        f += &a;
+
+        assert_eq!(f, b.add_with_order::<ColumnMajor, _>(&b)?);
+
+        let column = Array::<_, NDims<2>, ColumnMajor>::from_shape_vec([2, 1], vec![10, 20])?;
+        let g = &column + &array!([1, 2, 3]);
+
+        assert_eq!(g.shape(), &[2, 3]);
+        assert_eq!(
+            g.iter().cloned().collect::<Vec<_>>(),
+            vec![11, 12, 13, 21, 22, 23]
+        );
+        assert_eq!(
+            column
+                .add_with_order::<RowMajor, _>(array!([1, 2, 3]))?
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![11, 12, 13, 21, 22, 23]
+        );
+        assert!(a.add_with_order::<RowMajor, _>(array!([1, 2])).is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,475 @@
+use core::ops::{Add, Div, Mul, Rem, Sub};
+
+use num_complex::Complex;
+
+use super::ArrayBase;
+use crate::{storage::Storage, Array, Dimensionality, DimensionalityMax, NDArray, Order, Result};
+
+/// Common element type two element types are converted to before mixed-type
+/// arithmetic, used by the `*_promoted` methods such as
+/// [`ArrayBase::add_promoted`].
+///
+/// The arithmetic operators cannot promote their operands: they are already
+/// implemented for every pair of arrays whose element types support the
+/// operation, and coherence rules out a second, overlapping implementation
+/// for the pairs that do not.
+///
+/// Promotion picks the smallest type that holds both operands, as in the
+/// table below. `usize` and `isize` promote to `u64` and `i64` when mixed
+/// with other types, so the result does not depend on the pointer width of
+/// the target. An unsigned and a signed integer promote to a signed integer
+/// twice as wide, or to `f64` when no such integer exists. Integers wider
+/// than 16 bits promote to `f64` rather than `f32` when mixed with floats.
+///
+/// | | `u8` | `u16` | `u32` | `u64` | `usize` | `i8` | `i16` | `i32` | `i64` | `isize` | `f32` | `f64` |
+/// |---|---|---|---|---|---|---|---|---|---|---|---|---|
+/// | `u8` | u8 | u16 | u32 | u64 | u64 | i16 | i16 | i32 | i64 | i64 | f32 | f64 |
+/// | `u16` | u16 | u16 | u32 | u64 | u64 | i32 | i32 | i32 | i64 | i64 | f32 | f64 |
+/// | `u32` | u32 | u32 | u32 | u64 | u64 | i64 | i64 | i64 | i64 | i64 | f64 | f64 |
+/// | `u64` | u64 | u64 | u64 | u64 | u64 | f64 | f64 | f64 | f64 | f64 | f64 | f64 |
+/// | `usize` | u64 | u64 | u64 | u64 | usize | f64 | f64 | f64 | f64 | f64 | f64 | f64 |
+/// | `i8` | i16 | i32 | i64 | f64 | f64 | i8 | i16 | i32 | i64 | i64 | f32 | f64 |
+/// | `i16` | i16 | i32 | i64 | f64 | f64 | i16 | i16 | i32 | i64 | i64 | f32 | f64 |
+/// | `i32` | i32 | i32 | i64 | f64 | f64 | i32 | i32 | i32 | i64 | i64 | f64 | f64 |
+/// | `i64` | i64 | i64 | i64 | f64 | f64 | i64 | i64 | i64 | i64 | i64 | f64 | f64 |
+/// | `isize` | i64 | i64 | i64 | f64 | f64 | i64 | i64 | i64 | i64 | isize | f64 | f64 |
+/// | `f32` | f32 | f32 | f64 | f64 | f64 | f32 | f32 | f64 | f64 | f64 | f32 | f64 |
+/// | `f64` | f64 | f64 | f64 | f64 | f64 | f64 | f64 | f64 | f64 | f64 | f64 | f64 |
+///
+/// Mixing with `Complex<f32>` or `Complex<f64>` gives `Complex<f32>` when
+/// the real types promote to `f32`, and `Complex<f64>` otherwise.
+///
+/// Promotion to `f64` is lossy for 64-bit integers: `u64`, `i64`, `usize`
+/// and `isize` values beyond 2<sup>53</sup> in magnitude are rounded to the
+/// nearest representable float.
+pub trait Promote<Rhs = Self> {
+    type Output;
+    fn promote(self, rhs: Rhs) -> (Self::Output, Self::Output);
+}
+
+trait IntoPromoted<T> {
+    fn into_promoted(self) -> T;
+}
+
+impl<T> IntoPromoted<T> for T {
+    fn into_promoted(self) -> T {
+        self
+    }
+}
+
+macro_rules! impl_into_promoted {
+    ($out:ty; $( $type:ty ),*) => {
+        $(
+            impl IntoPromoted<$out> for $type {
+                fn into_promoted(self) -> $out {
+                    self as $out
+                }
+            }
+        )*
+    };
+}
+
+impl_into_promoted!(u16; u8);
+impl_into_promoted!(u32; u8, u16);
+impl_into_promoted!(u64; u8, u16, u32, usize);
+impl_into_promoted!(i16; u8, i8);
+impl_into_promoted!(i32; u8, u16, i8, i16);
+impl_into_promoted!(i64; u8, u16, u32, i8, i16, i32, isize);
+impl_into_promoted!(f32; u8, u16, i8, i16);
+impl_into_promoted!(f64; u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32);
+
+macro_rules! impl_into_promoted_complex {
+    ($real:ty; $( $type:ty ),*) => {
+        $(
+            impl IntoPromoted<Complex<$real>> for $type {
+                fn into_promoted(self) -> Complex<$real> {
+                    Complex::new(self as $real, 0.)
+                }
+            }
+        )*
+    };
+}
+
+impl_into_promoted_complex!(f32; u8, u16, i8, i16, f32);
+impl_into_promoted_complex!(f64; u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+impl IntoPromoted<Complex<f64>> for Complex<f32> {
+    fn into_promoted(self) -> Complex<f64> {
+        Complex::new(self.re as f64, self.im as f64)
+    }
+}
+
+macro_rules! impl_promote {
+    ($lhs:ty; $( $rhs:ty => $out:ty ),* $(,)?) => {
+        $(
+            impl Promote<$rhs> for $lhs {
+                type Output = $out;
+
+                fn promote(self, rhs: $rhs) -> ($out, $out) {
+                    (self.into_promoted(), rhs.into_promoted())
+                }
+            }
+        )*
+    };
+}
+
+impl_promote!(
+    u8;
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    usize => u64,
+    i8 => i16,
+    i16 => i16,
+    i32 => i32,
+    i64 => i64,
+    isize => i64,
+    f32 => f32,
+    f64 => f64,
+    Complex<f32> => Complex<f32>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    u16;
+    u8 => u16,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    usize => u64,
+    i8 => i32,
+    i16 => i32,
+    i32 => i32,
+    i64 => i64,
+    isize => i64,
+    f32 => f32,
+    f64 => f64,
+    Complex<f32> => Complex<f32>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    u32;
+    u8 => u32,
+    u16 => u32,
+    u32 => u32,
+    u64 => u64,
+    usize => u64,
+    i8 => i64,
+    i16 => i64,
+    i32 => i64,
+    i64 => i64,
+    isize => i64,
+    f32 => f64,
+    f64 => f64,
+    Complex<f32> => Complex<f64>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    u64;
+    u8 => u64,
+    u16 => u64,
+    u32 => u64,
+    u64 => u64,
+    usize => u64,
+    i8 => f64,
+    i16 => f64,
+    i32 => f64,
+    i64 => f64,
+    isize => f64,
+    f32 => f64,
+    f64 => f64,
+    Complex<f32> => Complex<f64>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    usize;
+    u8 => u64,
+    u16 => u64,
+    u32 => u64,
+    u64 => u64,
+    usize => usize,
+    i8 => f64,
+    i16 => f64,
+    i32 => f64,
+    i64 => f64,
+    isize => f64,
+    f32 => f64,
+    f64 => f64,
+    Complex<f32> => Complex<f64>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    i8;
+    u8 => i16,
+    u16 => i32,
+    u32 => i64,
+    u64 => f64,
+    usize => f64,
+    i8 => i8,
+    i16 => i16,
+    i32 => i32,
+    i64 => i64,
+    isize => i64,
+    f32 => f32,
+    f64 => f64,
+    Complex<f32> => Complex<f32>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    i16;
+    u8 => i16,
+    u16 => i32,
+    u32 => i64,
+    u64 => f64,
+    usize => f64,
+    i8 => i16,
+    i16 => i16,
+    i32 => i32,
+    i64 => i64,
+    isize => i64,
+    f32 => f32,
+    f64 => f64,
+    Complex<f32> => Complex<f32>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    i32;
+    u8 => i32,
+    u16 => i32,
+    u32 => i64,
+    u64 => f64,
+    usize => f64,
+    i8 => i32,
+    i16 => i32,
+    i32 => i32,
+    i64 => i64,
+    isize => i64,
+    f32 => f64,
+    f64 => f64,
+    Complex<f32> => Complex<f64>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    i64;
+    u8 => i64,
+    u16 => i64,
+    u32 => i64,
+    u64 => f64,
+    usize => f64,
+    i8 => i64,
+    i16 => i64,
+    i32 => i64,
+    i64 => i64,
+    isize => i64,
+    f32 => f64,
+    f64 => f64,
+    Complex<f32> => Complex<f64>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    isize;
+    u8 => i64,
+    u16 => i64,
+    u32 => i64,
+    u64 => f64,
+    usize => f64,
+    i8 => i64,
+    i16 => i64,
+    i32 => i64,
+    i64 => i64,
+    isize => isize,
+    f32 => f64,
+    f64 => f64,
+    Complex<f32> => Complex<f64>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    f32;
+    u8 => f32,
+    u16 => f32,
+    u32 => f64,
+    u64 => f64,
+    usize => f64,
+    i8 => f32,
+    i16 => f32,
+    i32 => f64,
+    i64 => f64,
+    isize => f64,
+    f32 => f32,
+    f64 => f64,
+    Complex<f32> => Complex<f32>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    f64;
+    u8 => f64,
+    u16 => f64,
+    u32 => f64,
+    u64 => f64,
+    usize => f64,
+    i8 => f64,
+    i16 => f64,
+    i32 => f64,
+    i64 => f64,
+    isize => f64,
+    f32 => f64,
+    f64 => f64,
+    Complex<f32> => Complex<f64>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    Complex<f32>;
+    u8 => Complex<f32>,
+    u16 => Complex<f32>,
+    u32 => Complex<f64>,
+    u64 => Complex<f64>,
+    usize => Complex<f64>,
+    i8 => Complex<f32>,
+    i16 => Complex<f32>,
+    i32 => Complex<f64>,
+    i64 => Complex<f64>,
+    isize => Complex<f64>,
+    f32 => Complex<f32>,
+    f64 => Complex<f64>,
+    Complex<f32> => Complex<f32>,
+    Complex<f64> => Complex<f64>,
+);
+
+impl_promote!(
+    Complex<f64>;
+    u8 => Complex<f64>,
+    u16 => Complex<f64>,
+    u32 => Complex<f64>,
+    u64 => Complex<f64>,
+    usize => Complex<f64>,
+    i8 => Complex<f64>,
+    i16 => Complex<f64>,
+    i32 => Complex<f64>,
+    i64 => Complex<f64>,
+    isize => Complex<f64>,
+    f32 => Complex<f64>,
+    f64 => Complex<f64>,
+    Complex<f32> => Complex<f64>,
+    Complex<f64> => Complex<f64>,
+);
+
+type Promoted<S, T> =
+    <<S as Storage>::Elem as Promote<<<T as NDArray>::Storage as Storage>::Elem>>::Output;
+
+macro_rules! impl_promoted_op {
+    ($( ($name:ident, $trait:ident, $op:ident) ),* $(,)?) => {
+        $(
+            #[doc = concat!(
+                "Applies [`", stringify!($trait), "`] elementwise after broadcasting both operands ",
+                "and converting their elements to the type given by [`Promote`]."
+            )]
+            #[allow(clippy::type_complexity)]
+            pub fn $name<T>(
+                &self,
+                rhs: &T,
+            ) -> Result<Array<Promoted<S, T>, <D as DimensionalityMax<T::Dimensionality>>::Output, O>>
+            where
+                D: DimensionalityMax<T::Dimensionality>,
+                <S as Storage>::Elem: Promote<<T::Storage as Storage>::Elem>,
+                Promoted<S, T>: Clone + $trait<Output = Promoted<S, T>>,
+                T: NDArray,
+            {
+                self.zip_with(rhs, |l, r| {
+                    let (l, r) = l.clone().promote(r.clone());
+                    l.$op(r)
+                })
+            }
+        )*
+    };
+}
+
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+{
+    impl_promoted_op!(
+        (add_promoted, Add, add),
+        (div_promoted, Div, div),
+        (mul_promoted, Mul, mul),
+        (rem_promoted, Rem, rem),
+        (sub_promoted, Sub, sub),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use num_complex::Complex;
+
+    use super::Promote;
+    use crate::{array, Array, NDArray, Result};
+
+    #[test]
+    fn promote_scalars() {
+        assert_eq!(1_i32.promote(0.5_f64), (1., 0.5));
+        assert_eq!(200_u8.promote(1_i8), (200_i16, 1_i16));
+        assert_eq!(
+            2_f32.promote(Complex::new(1_f32, 1.)),
+            (Complex::new(2., 0.), Complex::new(1., 1.))
+        );
+        assert_eq!(
+            Complex::new(1_f32, 2.).promote(3_i64),
+            (Complex::new(1_f64, 2.), Complex::new(3., 0.))
+        );
+        assert_eq!(u64::MAX.promote(-1_i64), (u64::MAX as f64, -1.));
+        assert_eq!(u32::MAX.promote(-1_isize), (u32::MAX as i64, -1_i64));
+        assert_eq!(1_usize.promote(2_u16), (1_u64, 2_u64));
+        assert_eq!(1_isize.promote(2_isize), (1_isize, 2_isize));
+    }
+
+    #[test]
+    fn promoted_ops() -> Result<()> {
+        let a = array!([[1_i32, 2], [3, 4]]);
+        let b = array!([0.5_f64, 0.25]);
+        let c = a.add_promoted(&b)?;
+
+        assert_eq!(c.shape(), &[2, 2]);
+        assert_eq!(
+            c.iter().cloned().collect::<Vec<_>>(),
+            vec![1.5, 2.25, 3.5, 4.25]
+        );
+        assert_eq!(
+            a.div_promoted(&b)?.iter().cloned().collect::<Vec<_>>(),
+            vec![2., 8., 6., 16.]
+        );
+
+        let d = array!([1_u8, 2]).sub_promoted(&array!([3_i8, 1]))?;
+
+        assert_eq!(d.iter().cloned().collect::<Vec<_>>(), vec![-2_i16, 1]);
+
+        let e = Array::from(vec![Complex::new(0_f32, 1.), Complex::new(1., 0.)]);
+
+        assert_eq!(
+            array!([2_f32, 3.])
+                .mul_promoted(&e)?
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![Complex::new(0., 2.), Complex::new(3., 0.)]
+        );
+        assert!(a.rem_promoted(&array!([1_u8, 2, 3])).is_err());
+
+        Ok(())
+    }
+}
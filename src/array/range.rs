@@ -0,0 +1,350 @@
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+use num_complex::Complex;
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+use num_traits::{Num, NumCast};
+
+use super::ArrayBase;
+use crate::{storage::StorageBase, Error, NDims, Result};
+
+impl<T> ArrayBase<StorageBase<Vec<T>>, NDims<1>> {
+    /// Values from `start` up to, but excluding, `stop` spaced by `step`.
+    ///
+    /// Only real element types are supported. Integer ranges are computed in
+    /// a wider type, so spans that do not fit in `T` still work.
+    /// Non-finite arguments and a zero step are rejected.
+    pub fn arange(start: T, stop: T, step: T) -> Result<Self>
+    where
+        T: PartialOrd + RangeElement,
+    {
+        if !(start.is_finite() && stop.is_finite() && step.is_finite()) {
+            return Err(Error::Value("range bounds and step must be finite".into()));
+        }
+        if step == T::zero() {
+            return Err(Error::Value("step must not be zero".into()));
+        }
+        let ascending = step > T::zero();
+        if (ascending && start >= stop) || (!ascending && start <= stop) {
+            return Ok(Self::from(Vec::new()));
+        }
+
+        let len = T::arange_len(start, stop, step)
+            .ok_or_else(|| Error::Value("range length cannot be represented".into()))?;
+        let data = (0..len)
+            .map(|i| {
+                T::arange_nth(start, step, i)
+                    .ok_or_else(|| Error::Value("range overflows element type".into()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::from(data))
+    }
+
+    /// `num` evenly spaced values over `[start, stop]`, or `[start, stop)`
+    /// unless `endpoint` is set.
+    ///
+    /// Complex element types are supported. Integer values are computed in a
+    /// wider type and truncated.
+    pub fn linspace(start: T, stop: T, num: usize, endpoint: bool) -> Self
+    where
+        T: RangeElement,
+    {
+        Self::from(linspace(start, stop, num, endpoint))
+    }
+
+    /// `num` values spaced evenly on a log scale, from `base^start` to
+    /// `base^stop`. Only real floating-point element types are supported.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn logspace(start: T, stop: T, num: usize, endpoint: bool, base: T) -> Self
+    where
+        T: Float + RangeElement,
+    {
+        Self::from(
+            linspace(start, stop, num, endpoint)
+                .into_iter()
+                .map(|y| base.powf(y))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// `num` values forming a geometric progression from `start` to `stop`.
+    /// Only real floating-point element types are supported.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn geomspace(start: T, stop: T, num: usize, endpoint: bool) -> Result<Self>
+    where
+        T: Float + RangeElement,
+    {
+        if start.is_zero() || stop.is_zero() {
+            return Err(Error::Value("geomspace bounds must not be zero".into()));
+        }
+        if start.is_sign_negative() != stop.is_sign_negative() {
+            return Err(Error::Value(
+                "geomspace bounds must have the same sign".into(),
+            ));
+        }
+
+        let sign = start.signum();
+        let ten = T::from(10).unwrap();
+        let mut data = linspace(start.abs().log10(), stop.abs().log10(), num, endpoint)
+            .into_iter()
+            .map(|y| sign * ten.powf(y))
+            .collect::<Vec<_>>();
+        if num > 0 {
+            data[0] = start;
+            if endpoint {
+                data[num - 1] = stop;
+            }
+        }
+        Ok(Self::from(data))
+    }
+}
+
+fn linspace<T>(start: T, stop: T, num: usize, endpoint: bool) -> Vec<T>
+where
+    T: RangeElement,
+{
+    let div = if endpoint { num.saturating_sub(1) } else { num };
+    if div == 0 {
+        return vec![start; num];
+    }
+
+    let mut data = (0..num)
+        .map(|i| T::linspace_nth(start, stop, i, div))
+        .collect::<Vec<_>>();
+    if endpoint {
+        data[num - 1] = stop;
+    }
+    data
+}
+
+/// Element types of the range constructors.
+///
+/// The provided methods compute the values in the element type itself, which
+/// suits floating-point and complex types. Integer types compute them in a
+/// wider type instead.
+pub trait RangeElement: Copy + Num + NumCast {
+    #[doc(hidden)]
+    fn is_finite(self) -> bool {
+        true
+    }
+
+    #[doc(hidden)]
+    fn arange_len(start: Self, stop: Self, step: Self) -> Option<usize> {
+        let len = (stop.to_f64()? - start.to_f64()?) / step.to_f64()?;
+        if len >= usize::MAX as f64 {
+            return None;
+        }
+        let truncated = len as usize;
+        Some(if (truncated as f64) < len {
+            truncated + 1
+        } else {
+            truncated
+        })
+    }
+
+    #[doc(hidden)]
+    fn arange_nth(start: Self, step: Self, i: usize) -> Option<Self> {
+        Some(start + step * Self::from(i)?)
+    }
+
+    #[doc(hidden)]
+    fn linspace_nth(start: Self, stop: Self, i: usize, div: usize) -> Self {
+        start + (stop - start) * Self::from(i).unwrap() / Self::from(div).unwrap()
+    }
+}
+
+macro_rules! impl_range_element_for_float {
+    ($( $type:ty ),*) => {
+        $(
+            impl RangeElement for $type {
+                fn is_finite(self) -> bool {
+                    <$type>::is_finite(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_range_element_for_float!(f32, f64);
+
+impl<T> RangeElement for Complex<T> where T: Copy + Num + NumCast {}
+
+// Every value of these types, and every product of a span with an index,
+// fits in `i128`
+macro_rules! impl_range_element_for_integer {
+    ($( $type:ty ),*) => {
+        $(
+            impl RangeElement for $type {
+                fn arange_len(start: $type, stop: $type, step: $type) -> Option<usize> {
+                    let (start, stop, step) = (start as i128, stop as i128, step as i128);
+                    usize::try_from((stop - start + step - step.signum()) / step).ok()
+                }
+
+                fn arange_nth(start: $type, step: $type, i: usize) -> Option<$type> {
+                    <$type>::try_from(start as i128 + step as i128 * i as i128).ok()
+                }
+
+                fn linspace_nth(start: $type, stop: $type, i: usize, div: usize) -> $type {
+                    let span = stop as i128 - start as i128;
+                    (start as i128 + span * i as i128 / div as i128) as $type
+                }
+            }
+        )*
+    };
+}
+
+impl_range_element_for_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_range_element_for_wide_integer {
+    ($( $type:ty ),*) => {
+        $(
+            impl RangeElement for $type {
+                fn arange_len(start: $type, stop: $type, step: $type) -> Option<usize> {
+                    let span = stop.checked_sub(start)?;
+                    usize::try_from(span / step + (span % step != 0) as $type).ok()
+                }
+
+                fn arange_nth(start: $type, step: $type, i: usize) -> Option<$type> {
+                    step.checked_mul(<$type>::try_from(i).ok()?)?.checked_add(start)
+                }
+
+                fn linspace_nth(start: $type, stop: $type, i: usize, div: usize) -> $type {
+                    let (i, div) = (i as $type, div as $type);
+                    let nth = if stop >= start {
+                        stop.checked_sub(start).and_then(|span| span.checked_mul(i)).map(|x| start + x / div)
+                    } else {
+                        start.checked_sub(stop).and_then(|span| span.checked_mul(i)).map(|x| start - x / div)
+                    };
+                    nth.unwrap_or_else(|| {
+                        let (start, stop) = (start as f64, stop as f64);
+                        (start + (stop - start) * i as f64 / div as f64) as $type
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_range_element_for_wide_integer!(i128, u128);
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use num_complex::Complex;
+
+    use crate::{Array, Error, NDArray, NDims, Result};
+
+    fn to_vec<T: Clone>(a: &Array<T, NDims<1>>) -> Vec<T> {
+        a.iter().cloned().collect()
+    }
+
+    #[test]
+    fn arange() -> Result<()> {
+        assert_eq!(to_vec(&Array::arange(0_i32, 5, 1)?), vec![0, 1, 2, 3, 4]);
+        assert_eq!(to_vec(&Array::arange(5_i32, 0, -2)?), vec![5, 3, 1]);
+        assert_eq!(to_vec(&Array::arange(0_u8, 250, 100)?), vec![0, 100, 200]);
+        assert_eq!(to_vec(&Array::arange(3_u8, 1, 1)?), vec![]);
+        assert_eq!(
+            to_vec(&Array::arange(0., 1., 0.25)?),
+            vec![0., 0.25, 0.5, 0.75]
+        );
+        assert_eq!(Array::arange(1., 2., 0.3)?.shape(), &[4]);
+        assert!(matches!(Array::arange(0, 1, 0), Err(Error::Value(_))));
+        assert!(matches!(
+            Array::arange(0., f64::INFINITY, 1.),
+            Err(Error::Value(_))
+        ));
+        assert!(matches!(
+            Array::arange(0_f32, 1., f32::NAN),
+            Err(Error::Value(_))
+        ));
+        assert!(matches!(
+            Array::arange(0., 1e300, 1e-300),
+            Err(Error::Value(_))
+        ));
+        assert_eq!(Array::arange(-100_i8, 100, 1)?.shape(), &[200]);
+        assert_eq!(
+            to_vec(&Array::arange(i8::MIN, i8::MAX, 100)?),
+            vec![-128, -28, 72]
+        );
+        assert_eq!(to_vec(&Array::arange(10_u8, 0, 255)?), vec![]);
+        assert_eq!(
+            to_vec(&Array::arange(i128::MAX - 2, i128::MAX, 1)?),
+            vec![i128::MAX - 2, i128::MAX - 1]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn linspace() {
+        assert_eq!(
+            to_vec(&Array::linspace(0., 1., 5, true)),
+            vec![0., 0.25, 0.5, 0.75, 1.]
+        );
+        assert_eq!(
+            to_vec(&Array::linspace(0., 1., 4, false)),
+            vec![0., 0.25, 0.5, 0.75]
+        );
+        assert_eq!(
+            *to_vec(&Array::linspace(0.1, 0.7, 7, true)).last().unwrap(),
+            0.7
+        );
+        assert_eq!(to_vec(&Array::linspace(0_i32, 10, 3, true)), vec![0, 5, 10]);
+        assert_eq!(to_vec(&Array::linspace(10_u8, 0, 3, true)), vec![10, 5, 0]);
+        assert_eq!(
+            to_vec(&Array::linspace(-100_i8, 100, 3, true)),
+            vec![-100, 0, 100]
+        );
+        assert_eq!(Array::linspace(0_u8, 255, 300, true).shape(), &[300]);
+        assert_eq!(
+            to_vec(&Array::linspace(u128::MAX, 0, 3, true)),
+            vec![u128::MAX, u128::MAX - u128::MAX / 2, 0]
+        );
+        assert_eq!(to_vec(&Array::linspace(2., 3., 1, true)), vec![2.]);
+        assert_eq!(to_vec(&Array::<f64, _>::linspace(2., 3., 0, true)), vec![]);
+        assert_eq!(
+            to_vec(&Array::linspace(
+                Complex::new(0., 0.),
+                Complex::new(2., 4.),
+                3,
+                true
+            )),
+            vec![
+                Complex::new(0., 0.),
+                Complex::new(1., 2.),
+                Complex::new(2., 4.)
+            ]
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn logspace_and_geomspace() -> Result<()> {
+        let a = to_vec(&Array::logspace(0., 3., 4, true, 10.));
+        for (x, y) in a.iter().zip([1_f64, 10., 100., 1000.]) {
+            assert!((x - y).abs() < 1e-9);
+        }
+        assert_eq!(
+            to_vec(&Array::logspace(0., 3., 3, false, 2.)),
+            vec![1., 2., 4.]
+        );
+
+        let g = to_vec(&Array::geomspace(-1., -1000., 4, true)?);
+        assert_eq!((g[0], g[3]), (-1., -1000.));
+        assert!((g[1] + 10_f64).abs() < 1e-9);
+        assert!(matches!(
+            Array::geomspace(0., 1., 3, true),
+            Err(Error::Value(_))
+        ));
+        assert!(matches!(
+            Array::geomspace(-1., 1., 3, true),
+            Err(Error::Value(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use crate::{Result, ShapeError};
 
 pub fn normalize_axis(axis: isize, n_dims: usize) -> Result<usize> {
     if axis < -(n_dims as isize) || axis >= n_dims as isize {
-        return Err(ShapeError::IncompatibleAxis(format!(
+        return Err(ShapeError::IncompatibleAxis(message!(
             "axis {axis} is out of bounds for array of dimension {n_dims}"
         ))
         .into());
@@ -0,0 +1,214 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::ArrayBase;
+use crate::{
+    storage::{Storage, StorageMut, StorageOwned},
+    util, ColumnMajor, Dimensionality, NDArray, Order, RowMajor,
+};
+
+#[derive(Deserialize, Serialize)]
+enum OrderTag {
+    RowMajor,
+    ColumnMajor,
+}
+
+impl OrderTag {
+    fn of<O>() -> Self
+    where
+        O: Order,
+    {
+        if util::type_eq::<O, ColumnMajor>() {
+            Self::ColumnMajor
+        } else {
+            Self::RowMajor
+        }
+    }
+}
+
+struct Elements<'a, S, D, O>(&'a ArrayBase<S, D, O>)
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage;
+
+impl<D, O, S> Serialize for Elements<'_, S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+    <S as Storage>::Elem: Serialize,
+{
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: Serializer,
+    {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+impl<D, O, S> Serialize for ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+    <S as Storage>::Elem: Serialize,
+{
+    fn serialize<Se>(&self, serializer: Se) -> Result<Se::Ok, Se::Error>
+    where
+        Se: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ArrayBase", 3)?;
+        state.serialize_field("shape", self.shape.as_ref())?;
+        state.serialize_field("order", &OrderTag::of::<O>())?;
+        state.serialize_field("data", &Elements(self))?;
+        state.end()
+    }
+}
+
+impl<'de, D, O, S> Deserialize<'de> for ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: StorageMut + StorageOwned,
+    <S as Storage>::Elem: Deserialize<'de>,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "ArrayBase")]
+        struct ArrayRepr<T> {
+            shape: Vec<usize>,
+            #[serde(rename = "order")]
+            _order: OrderTag,
+            data: Vec<T>,
+        }
+
+        let repr = ArrayRepr::<<S as Storage>::Elem>::deserialize(deserializer)?;
+        match D::NDIMS {
+            Some(n_dims) if n_dims != repr.shape.len() => {
+                return Err(de::Error::custom(format_args!(
+                    "expected {}-dimensional shape, but got {} axes",
+                    n_dims,
+                    repr.shape.len()
+                )))
+            }
+            _ => {}
+        }
+        let shape = D::shape_from_slice(&repr.shape)
+            .map_err(|e| de::Error::custom(format_args!("{:?}", e)))?;
+        let len = repr
+            .shape
+            .iter()
+            .try_fold(1_usize, |n, &dim| n.checked_mul(dim))
+            .ok_or_else(|| {
+                de::Error::custom(format_args!(
+                    "number of elements of shape {:?} overflows usize",
+                    repr.shape
+                ))
+            })?;
+        if len != repr.data.len() {
+            return Err(de::Error::custom(format_args!(
+                "shape {:?} requires {} elements, but got {}",
+                repr.shape,
+                len,
+                repr.data.len()
+            )));
+        }
+
+        // the data is in logical order, which is the memory order of a row-major array
+        Self::from_vec_with_order::<RowMajor>(shape, repr.data)
+            .map_err(|e| de::Error::custom(format_args!("{:?}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, Token};
+
+    use crate::{array, s, Array, ColumnMajor, DynDim, NDArray, NDims, RowMajor};
+
+    fn tokens(order: &'static str, shape: &[u64], data: &[i32]) -> Vec<Token> {
+        let mut tokens = vec![
+            Token::Struct {
+                name: "ArrayBase",
+                len: 3,
+            },
+            Token::Str("shape"),
+            Token::Seq {
+                len: Some(shape.len()),
+            },
+        ];
+        tokens.extend(shape.iter().map(|&dim| Token::U64(dim)));
+        tokens.extend([
+            Token::SeqEnd,
+            Token::Str("order"),
+            Token::UnitVariant {
+                name: "OrderTag",
+                variant: order,
+            },
+            Token::Str("data"),
+            Token::Seq {
+                len: Some(data.len()),
+            },
+        ]);
+        tokens.extend(data.iter().map(|&x| Token::I32(x)));
+        tokens.extend([Token::SeqEnd, Token::StructEnd]);
+        tokens
+    }
+
+    #[test]
+    fn serialize_and_deserialize() {
+        let a = array!([[1, 2, 3], [4, 5, 6]]);
+        let tokens = tokens("RowMajor", &[2, 3], &[1, 2, 3, 4, 5, 6]);
+
+        assert_ser_tokens(&a, &tokens);
+        assert_de_tokens(&a, &tokens);
+        assert_de_tokens(
+            &Array::<i32, DynDim, ColumnMajor>::from_vec_with_order::<RowMajor>(
+                vec![2, 3],
+                vec![1, 2, 3, 4, 5, 6],
+            )
+            .unwrap(),
+            &tokens,
+        );
+    }
+
+    #[test]
+    fn serialize_column_major_in_logical_order() {
+        let a = Array::<i32, NDims<2>, ColumnMajor>::from_vec_with_order::<RowMajor>(
+            [2, 2],
+            vec![1, 2, 3, 4],
+        )
+        .unwrap();
+
+        assert_eq!(a.strides(), &[1, 2]);
+        assert_ser_tokens(
+            &a.slice(s!(.., ..;-1)),
+            &tokens("ColumnMajor", &[2, 2], &[2, 1, 4, 3]),
+        );
+    }
+
+    #[test]
+    fn deserialize_invalid_array() {
+        assert_de_tokens_error::<Array<i32, NDims<3>>>(
+            &tokens("RowMajor", &[2, 3], &[1, 2, 3, 4, 5, 6]),
+            "expected 3-dimensional shape, but got 2 axes",
+        );
+        assert_de_tokens_error::<Array<i32, DynDim>>(
+            &tokens("RowMajor", &[2, 3], &[1, 2, 3, 4, 5]),
+            "shape [2, 3] requires 6 elements, but got 5",
+        );
+        assert_de_tokens_error::<Array<i32, DynDim>>(
+            &tokens("RowMajor", &[1 << 32, 1 << 32], &[]),
+            "number of elements of shape [4294967296, 4294967296] overflows usize",
+        );
+    }
+}
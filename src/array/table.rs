@@ -0,0 +1,350 @@
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+
+use super::{
+    fmt::{format_elements, ElementFormat, FormatElement, PrintOptions, Style},
+    ArrayBase,
+};
+use crate::{storage::Storage, Dimensionality, Error, Order, Result, ShapeError};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LatexStyle {
+    Bmatrix,
+    Tabular,
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TableOptions {
+    row_labels: Option<Vec<String>>,
+    column_labels: Option<Vec<String>>,
+    print_options: PrintOptions,
+}
+
+impl TableOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn column_labels<I>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.column_labels = Some(labels.into_iter().map(|l| l.to_string()).collect());
+        self
+    }
+
+    pub fn print_options(mut self, print_options: PrintOptions) -> Self {
+        self.print_options = print_options;
+        self
+    }
+
+    pub fn row_labels<I>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.row_labels = Some(labels.into_iter().map(|l| l.to_string()).collect());
+        self
+    }
+}
+
+impl<D, O, S> ArrayBase<S, D, O>
+where
+    D: Dimensionality,
+    O: Order,
+    S: Storage,
+    <S as Storage>::Elem: fmt::Display + FormatElement,
+{
+    pub fn to_html(&self, options: &TableOptions) -> Result<String> {
+        let (_, rows) = self.table_cells(options)?;
+        let escape = |s: &str| {
+            s.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        };
+        let row_header = |i: usize| match &options.row_labels {
+            Some(labels) => format!("<th>{}</th>", escape(&labels[i])),
+            None => String::new(),
+        };
+
+        let mut out = String::from("<table>\n");
+        if let Some(labels) = &options.column_labels {
+            out.push_str("<thead>\n<tr>");
+            if options.row_labels.is_some() {
+                out.push_str("<th></th>");
+            }
+            for label in labels {
+                out.push_str(&format!("<th>{}</th>", escape(label)));
+            }
+            out.push_str("</tr>\n</thead>\n");
+        }
+        out.push_str("<tbody>\n");
+        for (i, row) in rows.iter().enumerate() {
+            out.push_str("<tr>");
+            out.push_str(&row_header(i));
+            for cell in row {
+                out.push_str(&format!("<td>{}</td>", escape(cell)));
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</tbody>\n</table>");
+        Ok(out)
+    }
+
+    pub fn to_latex(&self, style: LatexStyle, options: &TableOptions) -> Result<String> {
+        let (n_cols, rows) = self.table_cells(options)?;
+        let escape = |s: &str| {
+            let mut escaped = String::with_capacity(s.len());
+            for c in s.chars() {
+                match c {
+                    '\\' => escaped.push_str("\\textbackslash{}"),
+                    '~' => escaped.push_str("\\textasciitilde{}"),
+                    '^' => escaped.push_str("\\textasciicircum{}"),
+                    '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                        escaped.push('\\');
+                        escaped.push(c);
+                    }
+                    c => escaped.push(c),
+                }
+            }
+            escaped
+        };
+
+        let mut lines = Vec::new();
+        match style {
+            LatexStyle::Bmatrix => {
+                if options.row_labels.is_some() || options.column_labels.is_some() {
+                    return Err(Error::Value(
+                        "labels cannot be rendered in a bmatrix".into(),
+                    ));
+                }
+                lines.push("\\begin{bmatrix}".to_string());
+                lines.push(
+                    rows.iter()
+                        .map(|row| {
+                            row.iter()
+                                .map(|cell| escape(cell))
+                                .collect::<Vec<_>>()
+                                .join(" & ")
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" \\\\\n"),
+                );
+                lines.push("\\end{bmatrix}".to_string());
+            }
+            LatexStyle::Tabular => {
+                let label_col = if options.row_labels.is_some() {
+                    "l"
+                } else {
+                    ""
+                };
+                lines.push(format!(
+                    "\\begin{{tabular}}{{{}{}}}",
+                    label_col,
+                    "r".repeat(n_cols)
+                ));
+                if let Some(labels) = &options.column_labels {
+                    let mut header = labels.iter().map(|l| escape(l)).collect::<Vec<_>>();
+                    if options.row_labels.is_some() {
+                        header.insert(0, String::new());
+                    }
+                    lines.push(format!("{} \\\\", header.join(" & ")));
+                    lines.push("\\hline".to_string());
+                }
+                for (i, row) in rows.iter().enumerate() {
+                    let mut cells = row.iter().map(|cell| escape(cell)).collect::<Vec<_>>();
+                    if let Some(labels) = &options.row_labels {
+                        cells.insert(0, escape(&labels[i]));
+                    }
+                    lines.push(format!("{} \\\\", cells.join(" & ")));
+                }
+                lines.push("\\end{tabular}".to_string());
+            }
+        }
+        Ok(lines.join("\n"))
+    }
+
+    pub fn to_markdown(&self, options: &TableOptions) -> Result<String> {
+        let (n_cols, rows) = self.table_cells(options)?;
+        let escape = |s: &str| s.replace('|', "\\|");
+        let line = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+
+        let mut header = match &options.column_labels {
+            Some(labels) => labels.iter().map(|l| escape(l)).collect::<Vec<_>>(),
+            None => (0..n_cols).map(|j| j.to_string()).collect(),
+        };
+        let mut separator = vec!["---:".to_string(); header.len()];
+        if options.row_labels.is_some() {
+            header.insert(0, String::new());
+            separator.insert(0, "---".to_string());
+        }
+
+        let mut lines = vec![line(header), line(separator)];
+        for (i, row) in rows.iter().enumerate() {
+            let mut cells = row.iter().map(|cell| escape(cell)).collect::<Vec<_>>();
+            if let Some(labels) = &options.row_labels {
+                cells.insert(0, escape(&labels[i]));
+            }
+            lines.push(line(cells));
+        }
+        Ok(lines.join("\n"))
+    }
+
+    fn table_cells(&self, options: &TableOptions) -> Result<(usize, Vec<Vec<String>>)> {
+        let (n_rows, n_cols) = match self.shape.as_ref() {
+            &[n_cols] => (1, n_cols),
+            &[n_rows, n_cols] => (n_rows, n_cols),
+            shape => {
+                return Err(ShapeError::IncompatibleDimension(format!(
+                    "cannot render {}-dimensional array as a table",
+                    shape.len()
+                ))
+                .into())
+            }
+        };
+        for (labels, n, axis) in [
+            (&options.row_labels, n_rows, "row"),
+            (&options.column_labels, n_cols, "column"),
+        ] {
+            if let Some(labels) = labels {
+                if labels.len() != n {
+                    return Err(ShapeError::IncompatibleShape(format!(
+                        "{} {} labels were given for {} {}s",
+                        labels.len(),
+                        axis,
+                        n,
+                        axis
+                    ))
+                    .into());
+                }
+            }
+        }
+
+        let strings = format_elements(
+            self,
+            &options.print_options,
+            usize::MAX / 2,
+            &ElementFormat {
+                alternate: false,
+                precision: None,
+                sign_plus: false,
+                style: Style::Auto,
+            },
+            &<<S as Storage>::Elem as fmt::Display>::fmt,
+        )
+        .map_err(|_| Error::Value("failed to format array elements".into()))?;
+        let rows = if n_cols == 0 {
+            vec![Vec::new(); n_rows]
+        } else {
+            strings.chunks(n_cols).map(<[_]>::to_vec).collect()
+        };
+        Ok((n_cols, rows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{string::String, vec};
+
+    use super::{LatexStyle, TableOptions};
+    use crate::{array, ArrayBase, Error, NDArrayOwned, PrintOptions, Result};
+
+    #[test]
+    fn render_markdown() -> Result<()> {
+        let a = ArrayBase::from(vec![1_i32, 2, 3, 4]).into_shape([2, 2])?;
+
+        assert_eq!(
+            a.to_markdown(&TableOptions::new())?,
+            "| 0 | 1 |\n| ---: | ---: |\n| 1 | 2 |\n| 3 | 4 |"
+        );
+        assert_eq!(
+            a.to_markdown(
+                &TableOptions::new()
+                    .row_labels(["x", "y"])
+                    .column_labels(["a|b", "c"])
+            )?,
+            "|  | a\\|b | c |\n| --- | ---: | ---: |\n| x | 1 | 2 |\n| y | 3 | 4 |"
+        );
+        assert_eq!(
+            array!([1.5_f64, 2.]).to_markdown(&TableOptions::new())?,
+            "| 0 | 1 |\n| ---: | ---: |\n| 1.5 | 2.0 |"
+        );
+        assert_eq!(
+            array!([1.5_f64, 2.]).to_markdown(
+                &TableOptions::new().print_options(PrintOptions::new().precision(3))
+            )?,
+            "| 0 | 1 |\n| ---: | ---: |\n| 1.500 | 2.000 |"
+        );
+        assert_eq!(
+            ArrayBase::from(vec![String::from("a|b"), String::from("c")])
+                .to_markdown(&TableOptions::new())?,
+            "| 0 | 1 |\n| ---: | ---: |\n| a\\|b | c |"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn render_latex() -> Result<()> {
+        let a = ArrayBase::from(vec![1_i32, 2, 3, 4]).into_shape([2, 2])?;
+
+        assert_eq!(
+            a.to_latex(LatexStyle::Bmatrix, &TableOptions::new())?,
+            "\\begin{bmatrix}\n1 & 2 \\\\\n3 & 4\n\\end{bmatrix}"
+        );
+        assert_eq!(
+            a.to_latex(
+                LatexStyle::Tabular,
+                &TableOptions::new()
+                    .row_labels(["x_1", "x_2"])
+                    .column_labels(["a", "b"])
+            )?,
+            "\\begin{tabular}{lrr}\n & a & b \\\\\n\\hline\nx\\_1 & 1 & 2 \\\\\n\
+             x\\_2 & 3 & 4 \\\\\n\\end{tabular}"
+        );
+        assert_eq!(
+            ArrayBase::from(vec![String::from("50%"), String::from("a_b")])
+                .to_latex(LatexStyle::Bmatrix, &TableOptions::new())?,
+            "\\begin{bmatrix}\n50\\% & a\\_b\n\\end{bmatrix}"
+        );
+        assert!(matches!(
+            a.to_latex(
+                LatexStyle::Bmatrix,
+                &TableOptions::new().row_labels(["x", "y"])
+            ),
+            Err(Error::Value(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn render_html() -> Result<()> {
+        let a = array!([1_u8, 2]);
+
+        assert_eq!(
+            a.to_html(&TableOptions::new().column_labels(["<a>", "b"]))?,
+            "<table>\n<thead>\n<tr><th>&lt;a&gt;</th><th>b</th></tr>\n</thead>\n\
+             <tbody>\n<tr><td>1</td><td>2</td></tr>\n</tbody>\n</table>"
+        );
+        assert!(matches!(
+            a.to_html(&TableOptions::new().row_labels(["x", "y"])),
+            Err(Error::Shape(_))
+        ));
+        assert!(matches!(
+            array!([[[1_u8]]]).to_html(&TableOptions::new()),
+            Err(Error::Shape(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -1,11 +1,15 @@
 use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::Slice;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct NewAxis;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum ArrayIndex {
     Index(isize),
     Slice(Slice),
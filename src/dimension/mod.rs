@@ -1,18 +1,50 @@
 mod ops;
 pub use ops::{DimensionalityAdd, DimensionalityAfterDot, DimensionalityMax};
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 
-use crate::{Shape, SignedShape};
+use crate::{Result, Shape, ShapeError, SignedShape};
 
-pub trait Dimensionality: DimensionalityAdd<DynDimDiff> {
+// Any number of axes can be added to an array once dynamic dimensions are
+// available, which needs an allocator
+#[cfg(feature = "alloc")]
+pub trait DimensionalityBase: DimensionalityAdd<DynDimDiff> {}
+
+#[cfg(feature = "alloc")]
+impl<D> DimensionalityBase for D where D: DimensionalityAdd<DynDimDiff> {}
+
+#[cfg(not(feature = "alloc"))]
+pub trait DimensionalityBase {}
+
+#[cfg(not(feature = "alloc"))]
+impl<D> DimensionalityBase for D {}
+
+pub trait Dimensionality: DimensionalityBase {
     type Shape: Shape;
     type SignedShape: SignedShape;
+    const NDIMS: Option<usize>;
     fn shape_zeroed(n_dims: usize) -> Self::Shape;
     fn signed_shape_zeroed(n_dims: usize) -> Self::SignedShape;
     fn strides_zeroed(n_dims: usize) -> <Self::Shape as Shape>::Strides;
 
+    fn shape_from_slice(shape: &[usize]) -> Result<Self::Shape> {
+        if let Some(n_dims) = Self::NDIMS {
+            if n_dims != shape.len() {
+                return Err(ShapeError::IncompatibleDimension(message!(
+                    "expected {}-dimensional shape, but got {:?}",
+                    n_dims,
+                    shape
+                ))
+                .into());
+            }
+        }
+
+        let mut out_shape = Self::shape_zeroed(shape.len());
+        out_shape.as_mut().copy_from_slice(shape);
+        Ok(out_shape)
+    }
+
     fn first_indices(shape: &Self::Shape) -> Option<Self::Shape> {
         if shape.as_ref().iter().any(|&x| x == 0) {
             None
@@ -28,6 +60,7 @@ pub struct NDims<const N: usize>;
 impl<const N: usize> Dimensionality for NDims<N> {
     type Shape = [usize; N];
     type SignedShape = [isize; N];
+    const NDIMS: Option<usize> = Some(N);
 
     fn shape_zeroed(n_dims: usize) -> Self::Shape {
         assert_eq!(n_dims, N);
@@ -45,12 +78,15 @@ impl<const N: usize> Dimensionality for NDims<N> {
     }
 }
 
+#[cfg(feature = "alloc")]
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct DynDim;
 
+#[cfg(feature = "alloc")]
 impl Dimensionality for DynDim {
     type Shape = Vec<usize>;
     type SignedShape = Vec<isize>;
+    const NDIMS: Option<usize> = None;
 
     fn shape_zeroed(n_dims: usize) -> Self::Shape {
         let mut shape = Vec::<usize>::new();
@@ -74,19 +110,27 @@ impl Dimensionality for DynDim {
     }
 }
 
-pub trait DimensionalityDiff {}
+pub trait DimensionalityDiff {
+    const DIFF: Option<isize>;
+}
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct DimDiff<const N: isize>;
 
-impl<const N: isize> DimensionalityDiff for DimDiff<N> {}
+impl<const N: isize> DimensionalityDiff for DimDiff<N> {
+    const DIFF: Option<isize> = Some(N);
+}
 
+#[cfg(feature = "alloc")]
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct DynDimDiff;
 
-impl DimensionalityDiff for DynDimDiff {}
+#[cfg(feature = "alloc")]
+impl DimensionalityDiff for DynDimDiff {
+    const DIFF: Option<isize> = None;
+}
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     #[cfg(not(feature = "std"))]
     use alloc::vec;
@@ -117,6 +161,13 @@ mod tests {
         NDims::<3>::strides_zeroed(4);
     }
 
+    #[test]
+    fn get_shape_from_slice() {
+        assert_eq!(NDims::<3>::shape_from_slice(&[2, 3, 4]).unwrap(), [2, 3, 4]);
+        assert_eq!(DynDim::shape_from_slice(&[2, 3]).unwrap(), vec![2, 3]);
+        assert!(NDims::<2>::shape_from_slice(&[2, 3, 4]).is_err());
+    }
+
     #[test]
     fn get_first_indices() {
         {
@@ -3,7 +3,9 @@ use crate::{
     Dimensionality,
 };
 
-use super::{DimDiff, DynDim, DynDimDiff, NDims};
+use super::{DimDiff, NDims};
+#[cfg(feature = "alloc")]
+use super::{DynDim, DynDimDiff};
 
 pub trait DimensionalityAdd<Rhs> {
     type Output: Dimensionality;
@@ -17,14 +19,17 @@ where
     type Output = NDims<{ (N as isize + M) as usize }>;
 }
 
+#[cfg(feature = "alloc")]
 impl<const N: usize> DimensionalityAdd<DynDimDiff> for NDims<N> {
     type Output = DynDim;
 }
 
+#[cfg(feature = "alloc")]
 impl<const N: isize> DimensionalityAdd<DimDiff<N>> for DynDim {
     type Output = DynDim;
 }
 
+#[cfg(feature = "alloc")]
 impl DimensionalityAdd<DynDimDiff> for DynDim {
     type Output = DynDim;
 }
@@ -53,14 +58,17 @@ impl<const N: usize> DimensionalityAfterDot<NDims<N>> for NDims<0> {
     type Output = NDims<N>;
 }
 
+#[cfg(feature = "alloc")]
 impl<const N: usize> DimensionalityAfterDot<DynDim> for NDims<N> {
     type Output = DynDim;
 }
 
+#[cfg(feature = "alloc")]
 impl<const N: usize> DimensionalityAfterDot<NDims<N>> for DynDim {
     type Output = DynDim;
 }
 
+#[cfg(feature = "alloc")]
 impl DimensionalityAfterDot<DynDim> for DynDim {
     type Output = DynDim;
 }
@@ -84,14 +92,17 @@ where
     type Output = NDims<{ max(M, N) }>;
 }
 
+#[cfg(feature = "alloc")]
 impl<const N: usize> DimensionalityMax<DynDim> for NDims<N> {
     type Output = DynDim;
 }
 
+#[cfg(feature = "alloc")]
 impl<const N: usize> DimensionalityMax<NDims<N>> for DynDim {
     type Output = DynDim;
 }
 
+#[cfg(feature = "alloc")]
 impl DimensionalityMax<DynDim> for DynDim {
     type Output = DynDim;
 }
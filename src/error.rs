@@ -1,14 +1,49 @@
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::string::String;
 use core::num::TryFromIntError;
+#[cfg(feature = "std")]
+use std::{io, sync::Arc};
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// Message carried by an [`Error`]. Without the `alloc` feature it is the
+/// bare format string of the message, without its arguments.
+#[cfg(feature = "alloc")]
+pub type Message = String;
+#[cfg(not(feature = "alloc"))]
+pub type Message = &'static str;
+
+#[cfg(feature = "alloc")]
+macro_rules! message {
+    ($($arg:tt)*) => {
+        format!($($arg)*)
+    };
+}
+
+#[cfg(not(feature = "alloc"))]
+macro_rules! message {
+    ($fmt:literal $(, $arg:expr)* $(,)?) => {{
+        $(let _ = &$arg;)*
+        $fmt
+    }};
+}
+
 #[derive(Clone, Debug)]
 pub enum Error {
+    Dtype(Message),
+    Format(Message),
+    #[cfg(feature = "std")]
+    Io(Arc<io::Error>),
     Shape(ShapeError),
     TryFromInt(TryFromIntError),
-    Value(String),
+    Value(Message),
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(Arc::new(e))
+    }
 }
 
 impl From<ShapeError> for Error {
@@ -25,7 +60,7 @@ impl From<TryFromIntError> for Error {
 
 #[derive(Clone, Debug)]
 pub enum ShapeError {
-    IncompatibleAxis(String),
-    IncompatibleDimension(String),
-    IncompatibleShape(String),
+    IncompatibleAxis(Message),
+    IncompatibleDimension(Message),
+    IncompatibleShape(Message),
 }
@@ -0,0 +1,1161 @@
+use std::{
+    io::{self, Read, Write},
+    mem,
+};
+
+use super::{
+    bytes::{to_bytes, vec_from_bytes, Endian, Pod},
+    flatbuffers::{Builder, Table},
+    read_vec,
+};
+use crate::{storage::Storage, Array, DynDim, Error, NDArray, Result, RowMajor, ShapeError};
+
+const ALIGNMENT: usize = 8;
+const CONTINUATION: [u8; 4] = [0xff; 4];
+const MAGIC: &[u8] = b"ARROW1";
+const METADATA_VERSION_V5: i16 = 4;
+
+const HEADER_SCHEMA: u8 = 1;
+const HEADER_DICTIONARY_BATCH: u8 = 2;
+const HEADER_RECORD_BATCH: u8 = 3;
+
+const TYPE_INT: u8 = 2;
+const TYPE_FLOATING_POINT: u8 = 3;
+const TYPE_FIXED_SIZE_LIST: u8 = 16;
+
+const PRECISION_HALF: i16 = 0;
+const PRECISION_SINGLE: i16 = 1;
+const PRECISION_DOUBLE: i16 = 2;
+
+const MESSAGE_VERSION: u16 = 0;
+const MESSAGE_HEADER_TYPE: u16 = 1;
+const MESSAGE_HEADER: u16 = 2;
+const MESSAGE_BODY_LENGTH: u16 = 3;
+const SCHEMA_ENDIANNESS: u16 = 0;
+const SCHEMA_FIELDS: u16 = 1;
+const FIELD_NAME: u16 = 0;
+const FIELD_TYPE_TYPE: u16 = 2;
+const FIELD_TYPE: u16 = 3;
+const FIELD_DICTIONARY: u16 = 4;
+const FIELD_CHILDREN: u16 = 5;
+const INT_BIT_WIDTH: u16 = 0;
+const INT_IS_SIGNED: u16 = 1;
+const FLOATING_POINT_PRECISION: u16 = 0;
+const FIXED_SIZE_LIST_SIZE: u16 = 0;
+const RECORD_BATCH_LENGTH: u16 = 0;
+const RECORD_BATCH_NODES: u16 = 1;
+const RECORD_BATCH_BUFFERS: u16 = 2;
+const RECORD_BATCH_COMPRESSION: u16 = 3;
+const FOOTER_VERSION: u16 = 0;
+const FOOTER_SCHEMA: u16 = 1;
+const FOOTER_RECORD_BATCHES: u16 = 3;
+
+const FIELD_NODE_SIZE: usize = 16;
+const BUFFER_SIZE: usize = 16;
+const BLOCK_SIZE: usize = 24;
+
+pub trait ArrowElement: Pod {
+    const FLOATING_POINT: bool;
+    const SIGNED: bool;
+}
+
+macro_rules! impl_arrow_element {
+    ($type:ty, $floating_point:expr, $signed:expr) => {
+        impl ArrowElement for $type {
+            const FLOATING_POINT: bool = $floating_point;
+            const SIGNED: bool = $signed;
+        }
+    };
+}
+
+impl_arrow_element!(u8, false, false);
+impl_arrow_element!(u16, false, false);
+impl_arrow_element!(u32, false, false);
+impl_arrow_element!(u64, false, false);
+impl_arrow_element!(i8, false, true);
+impl_arrow_element!(i16, false, true);
+impl_arrow_element!(i32, false, true);
+impl_arrow_element!(i64, false, true);
+impl_arrow_element!(f32, true, true);
+impl_arrow_element!(f64, true, true);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct ElementType {
+    floating_point: bool,
+    signed: bool,
+    bit_width: usize,
+}
+
+impl ElementType {
+    fn of<T>() -> Self
+    where
+        T: ArrowElement,
+    {
+        Self {
+            floating_point: T::FLOATING_POINT,
+            signed: T::SIGNED,
+            bit_width: mem::size_of::<T>() * 8,
+        }
+    }
+
+    fn name(&self) -> String {
+        match (self.floating_point, self.signed) {
+            (true, _) => format!("float{}", self.bit_width),
+            (false, true) => format!("int{}", self.bit_width),
+            (false, false) => format!("uint{}", self.bit_width),
+        }
+    }
+}
+
+struct Column {
+    name: String,
+    shape: Vec<usize>,
+    ty: ElementType,
+    data: Vec<u8>,
+}
+
+fn build_field(builder: &mut Builder, name: &str, dims: &[usize], element: ElementType) -> usize {
+    let (type_type, ty, children) = match dims.split_first() {
+        Some((&size, rest)) => {
+            let child = build_field(builder, "item", rest, element);
+            let children = builder.create_vector_of_offsets(&[child]);
+            builder.start_table();
+            builder.add_i32(FIXED_SIZE_LIST_SIZE, size as i32);
+            (TYPE_FIXED_SIZE_LIST, builder.end_table(), children)
+        }
+        None => {
+            let children = builder.create_vector_of_offsets(&[]);
+            builder.start_table();
+            let type_type = if element.floating_point {
+                let precision = if element.bit_width == 32 {
+                    PRECISION_SINGLE
+                } else {
+                    PRECISION_DOUBLE
+                };
+                builder.add_i16(FLOATING_POINT_PRECISION, precision);
+                TYPE_FLOATING_POINT
+            } else {
+                builder.add_i32(INT_BIT_WIDTH, element.bit_width as i32);
+                builder.add_bool(INT_IS_SIGNED, element.signed);
+                TYPE_INT
+            };
+            (type_type, builder.end_table(), children)
+        }
+    };
+    let name = builder.create_string(name);
+
+    builder.start_table();
+    builder.add_offset(FIELD_NAME, name);
+    builder.add_offset(FIELD_TYPE, ty);
+    builder.add_offset(FIELD_CHILDREN, children);
+    builder.add_u8(FIELD_TYPE_TYPE, type_type);
+    builder.end_table()
+}
+
+fn build_schema(builder: &mut Builder, columns: &[Column]) -> usize {
+    let fields = columns
+        .iter()
+        .map(|column| build_field(builder, &column.name, &column.shape[1..], column.ty))
+        .collect::<Vec<_>>();
+    let fields = builder.create_vector_of_offsets(&fields);
+
+    builder.start_table();
+    builder.add_offset(SCHEMA_FIELDS, fields);
+    builder.end_table()
+}
+
+fn build_message<F>(header_type: u8, body_len: usize, build_header: F) -> Vec<u8>
+where
+    F: FnOnce(&mut Builder) -> usize,
+{
+    let mut builder = Builder::new();
+    let header = build_header(&mut builder);
+
+    builder.start_table();
+    builder.add_i64(MESSAGE_BODY_LENGTH, body_len as i64);
+    builder.add_offset(MESSAGE_HEADER, header);
+    builder.add_i16(MESSAGE_VERSION, METADATA_VERSION_V5);
+    builder.add_u8(MESSAGE_HEADER_TYPE, header_type);
+    let message = builder.end_table();
+    builder.finish(message)
+}
+
+fn build_record_batch(columns: &[Column]) -> (Vec<u8>, Vec<u8>) {
+    let mut nodes = Vec::new();
+    let mut buffers = Vec::new();
+    let mut body = Vec::new();
+    for column in columns {
+        let mut len = column.shape[0];
+        for &dim in &column.shape[1..] {
+            push_pair(&mut nodes, len, 0);
+            push_pair(&mut buffers, body.len(), 0);
+            len *= dim;
+        }
+        push_pair(&mut nodes, len, 0);
+        push_pair(&mut buffers, body.len(), 0);
+        push_pair(&mut buffers, body.len(), column.data.len());
+        body.extend_from_slice(&column.data);
+        body.resize(padded_len(body.len()), 0);
+    }
+
+    let n_rows = columns.first().map_or(0, |column| column.shape[0]);
+    let metadata = build_message(HEADER_RECORD_BATCH, body.len(), |builder| {
+        let nodes = builder.create_vector_of_structs(&nodes, nodes.len() / FIELD_NODE_SIZE, 8);
+        let buffers = builder.create_vector_of_structs(&buffers, buffers.len() / BUFFER_SIZE, 8);
+        builder.start_table();
+        builder.add_i64(RECORD_BATCH_LENGTH, n_rows as i64);
+        builder.add_offset(RECORD_BATCH_NODES, nodes);
+        builder.add_offset(RECORD_BATCH_BUFFERS, buffers);
+        builder.end_table()
+    });
+    (metadata, body)
+}
+
+fn push_pair(out: &mut Vec<u8>, a: usize, b: usize) {
+    out.extend_from_slice(&(a as i64).to_le_bytes());
+    out.extend_from_slice(&(b as i64).to_le_bytes());
+}
+
+fn padded_len(len: usize) -> usize {
+    len.div_ceil(ALIGNMENT) * ALIGNMENT
+}
+
+fn write_message<W>(writer: &mut W, metadata: &[u8], body: &[u8]) -> Result<usize>
+where
+    W: Write,
+{
+    let len = padded_len(CONTINUATION.len() + 4 + metadata.len());
+    writer.write_all(&CONTINUATION)?;
+    writer.write_all(&((len - CONTINUATION.len() - 4) as i32).to_le_bytes())?;
+    writer.write_all(metadata)?;
+    writer.write_all(&vec![0; len - CONTINUATION.len() - 4 - metadata.len()])?;
+    writer.write_all(body)?;
+    Ok(len)
+}
+
+fn write_end_of_stream<W>(writer: &mut W) -> Result<()>
+where
+    W: Write,
+{
+    writer.write_all(&CONTINUATION)?;
+    writer.write_all(&0_i32.to_le_bytes())?;
+    Ok(())
+}
+
+pub struct ArrowWriter<W> {
+    writer: W,
+    columns: Vec<Column>,
+}
+
+impl<W> ArrowWriter<W>
+where
+    W: Write,
+{
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            columns: Vec::new(),
+        }
+    }
+
+    pub fn add_column<A>(&mut self, name: &str, array: &A) -> Result<()>
+    where
+        A: NDArray,
+        <A::Storage as Storage>::Elem: ArrowElement,
+    {
+        let shape = array.shape().as_ref().to_vec();
+        match (shape.first(), self.columns.first()) {
+            (None, _) => {
+                return Err(ShapeError::IncompatibleDimension(format!(
+                    "cannot write 0-dimensional array '{}' as Arrow column",
+                    name
+                ))
+                .into())
+            }
+            (Some(&n), Some(first)) if n != first.shape[0] => {
+                return Err(ShapeError::IncompatibleShape(format!(
+                    "column '{}' has {} rows, but expected {}",
+                    name, n, first.shape[0]
+                ))
+                .into())
+            }
+            _ => {}
+        }
+        self.columns.push(Column {
+            name: name.to_string(),
+            shape,
+            ty: ElementType::of::<<A::Storage as Storage>::Elem>(),
+            data: to_bytes::<RowMajor, _>(array, Endian::Little),
+        });
+        Ok(())
+    }
+
+    pub fn finish_file(mut self) -> Result<W> {
+        let schema = build_message(HEADER_SCHEMA, 0, |builder| {
+            build_schema(builder, &self.columns)
+        });
+        let (batch, body) = build_record_batch(&self.columns);
+
+        let header = padded_len(MAGIC.len());
+        self.writer.write_all(MAGIC)?;
+        self.writer.write_all(&vec![0; header - MAGIC.len()])?;
+        let offset = header + write_message(&mut self.writer, &schema, &[])?;
+        let metadata_len = write_message(&mut self.writer, &batch, &body)?;
+        write_end_of_stream(&mut self.writer)?;
+
+        let mut builder = Builder::new();
+        let schema = build_schema(&mut builder, &self.columns);
+        let mut block = Vec::with_capacity(BLOCK_SIZE);
+        block.extend_from_slice(&(offset as i64).to_le_bytes());
+        block.extend_from_slice(&(metadata_len as i32).to_le_bytes());
+        block.extend_from_slice(&[0; 4]);
+        block.extend_from_slice(&(body.len() as i64).to_le_bytes());
+        let blocks = builder.create_vector_of_structs(&block, 1, 8);
+        builder.start_table();
+        builder.add_offset(FOOTER_SCHEMA, schema);
+        builder.add_offset(FOOTER_RECORD_BATCHES, blocks);
+        builder.add_i16(FOOTER_VERSION, METADATA_VERSION_V5);
+        let footer = builder.end_table();
+        let footer = builder.finish(footer);
+
+        self.writer.write_all(&footer)?;
+        self.writer
+            .write_all(&(footer.len() as i32).to_le_bytes())?;
+        self.writer.write_all(MAGIC)?;
+        Ok(self.writer)
+    }
+
+    pub fn finish_stream(mut self) -> Result<W> {
+        let schema = build_message(HEADER_SCHEMA, 0, |builder| {
+            build_schema(builder, &self.columns)
+        });
+        let (batch, body) = build_record_batch(&self.columns);
+
+        write_message(&mut self.writer, &schema, &[])?;
+        write_message(&mut self.writer, &batch, &body)?;
+        write_end_of_stream(&mut self.writer)?;
+        Ok(self.writer)
+    }
+}
+
+pub fn write_arrow_stream<'a, A, I, N, W>(writer: W, columns: I) -> Result<()>
+where
+    A: NDArray + 'a,
+    <A::Storage as Storage>::Elem: ArrowElement,
+    I: IntoIterator<Item = (N, &'a A)>,
+    N: AsRef<str>,
+    W: Write,
+{
+    let mut arrow = ArrowWriter::new(writer);
+    for (name, array) in columns {
+        arrow.add_column(name.as_ref(), array)?;
+    }
+    arrow.finish_stream()?;
+    Ok(())
+}
+
+pub fn write_arrow_file<'a, A, I, N, W>(writer: W, columns: I) -> Result<()>
+where
+    A: NDArray + 'a,
+    <A::Storage as Storage>::Elem: ArrowElement,
+    I: IntoIterator<Item = (N, &'a A)>,
+    N: AsRef<str>,
+    W: Write,
+{
+    let mut arrow = ArrowWriter::new(writer);
+    for (name, array) in columns {
+        arrow.add_column(name.as_ref(), array)?;
+    }
+    arrow.finish_file()?;
+    Ok(())
+}
+
+struct Field {
+    name: String,
+    dims: Vec<usize>,
+    ty: ElementType,
+    data: Vec<u8>,
+}
+
+pub struct ArrowTable {
+    fields: Vec<Field>,
+    endian: Endian,
+    len: usize,
+}
+
+impl ArrowTable {
+    pub fn read_file<R>(mut reader: R) -> Result<Self>
+    where
+        R: Read,
+    {
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+
+        let trailer_len = 4 + MAGIC.len();
+        if buf.len() < padded_len(MAGIC.len()) + trailer_len
+            || !buf.starts_with(MAGIC)
+            || !buf.ends_with(MAGIC)
+        {
+            return Err(Error::Format("invalid Arrow file magic".into()));
+        }
+        let footer_end = buf.len() - trailer_len;
+        let footer_len = i32::from_le_bytes(buf[footer_end..footer_end + 4].try_into().unwrap());
+        let footer = footer_end
+            .checked_sub(usize::try_from(footer_len)?)
+            .map(|footer_start| &buf[footer_start..footer_end])
+            .ok_or_else(|| Error::Format("invalid Arrow file footer length".into()))?;
+        let footer = Table::root(footer)?;
+
+        let schema = footer
+            .table(FOOTER_SCHEMA)?
+            .ok_or_else(|| Error::Format("Arrow file footer has no schema".into()))?;
+        let mut table = Self::from_schema(schema)?;
+        for block in footer.structs(FOOTER_RECORD_BATCHES, BLOCK_SIZE)? {
+            let offset = usize::try_from(i64::from_le_bytes(block[..8].try_into().unwrap()))?;
+            let metadata_len =
+                usize::try_from(i32::from_le_bytes(block[8..12].try_into().unwrap()))?;
+            let body_len = usize::try_from(i64::from_le_bytes(block[16..24].try_into().unwrap()))?;
+            let mut message = offset
+                .checked_add(metadata_len)
+                .and_then(|end| end.checked_add(body_len))
+                .and_then(|end| buf[..footer_end].get(offset..end))
+                .ok_or_else(|| Error::Format("Arrow record batch is out of bounds".into()))?;
+            match read_message(&mut message)? {
+                Some((metadata, body)) => match message_header(&metadata)? {
+                    (HEADER_RECORD_BATCH, batch) => table.push_record_batch(batch, &body)?,
+                    _ => return Err(Error::Format("expected Arrow record batch".into())),
+                },
+                None => return Err(Error::Format("expected Arrow record batch".into())),
+            }
+        }
+        Ok(table)
+    }
+
+    pub fn read_stream<R>(mut reader: R) -> Result<Self>
+    where
+        R: Read,
+    {
+        let mut table = match read_message(&mut reader)? {
+            Some((metadata, _)) => match message_header(&metadata)? {
+                (HEADER_SCHEMA, schema) => Self::from_schema(schema)?,
+                _ => {
+                    return Err(Error::Format(
+                        "Arrow stream must start with a schema message".into(),
+                    ))
+                }
+            },
+            None => return Err(Error::Format("Arrow stream is empty".into())),
+        };
+
+        while let Some((metadata, body)) = read_message(&mut reader)? {
+            match message_header(&metadata)? {
+                (HEADER_RECORD_BATCH, batch) => table.push_record_batch(batch, &body)?,
+                (HEADER_DICTIONARY_BATCH, _) => {
+                    return Err(Error::Format("dictionary batches are not supported".into()))
+                }
+                (header_type, _) => {
+                    return Err(Error::Format(format!(
+                        "unexpected Arrow message of header type {}",
+                        header_type
+                    )))
+                }
+            }
+        }
+        Ok(table)
+    }
+
+    pub fn column<T>(&self, name: &str) -> Result<Array<T, DynDim>>
+    where
+        T: ArrowElement,
+    {
+        let field = self
+            .fields
+            .iter()
+            .find(|field| field.name == name)
+            .ok_or_else(|| Error::Value(format!("no column named '{}'", name)))?;
+        self.decode::<T>(field)
+    }
+
+    pub fn columns<T>(&self) -> Result<Vec<(String, Array<T, DynDim>)>>
+    where
+        T: ArrowElement,
+    {
+        self.fields
+            .iter()
+            .map(|field| Ok((field.name.clone(), self.decode::<T>(field)?)))
+            .collect()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|field| field.name.as_str())
+    }
+
+    fn decode<T>(&self, field: &Field) -> Result<Array<T, DynDim>>
+    where
+        T: ArrowElement,
+    {
+        if field.ty != ElementType::of::<T>() {
+            return Err(Error::Dtype(format!(
+                "cannot read column '{}' of type {} as {}",
+                field.name,
+                field.ty.name(),
+                ElementType::of::<T>().name()
+            )));
+        }
+        let mut shape = vec![self.len];
+        shape.extend_from_slice(&field.dims);
+        Array::from_vec_with_order::<RowMajor>(shape, vec_from_bytes(&field.data, self.endian))
+    }
+
+    fn from_schema(schema: Table) -> Result<Self> {
+        let endian = match schema.i16(SCHEMA_ENDIANNESS, 0)? {
+            0 => Endian::Little,
+            1 => Endian::Big,
+            e => return Err(Error::Format(format!("invalid Arrow endianness {}", e))),
+        };
+        let fields = schema
+            .tables(SCHEMA_FIELDS)?
+            .into_iter()
+            .map(|field| {
+                let name = field.string(FIELD_NAME)?.unwrap_or_default().to_string();
+                let mut dims = Vec::new();
+                let ty = Self::decode_field(&name, field, &mut dims)?;
+                Ok(Field {
+                    name,
+                    dims,
+                    ty,
+                    data: Vec::new(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            fields,
+            endian,
+            len: 0,
+        })
+    }
+
+    fn decode_field(name: &str, field: Table, dims: &mut Vec<usize>) -> Result<ElementType> {
+        if field.has(FIELD_DICTIONARY)? {
+            return Err(Error::Dtype(format!(
+                "cannot read dictionary-encoded column '{}'",
+                name
+            )));
+        }
+        let ty = field
+            .table(FIELD_TYPE)?
+            .ok_or_else(|| Error::Format(format!("column '{}' has no type", name)))?;
+        let (floating_point, signed, bit_width) = match field.u8(FIELD_TYPE_TYPE, 0)? {
+            TYPE_FIXED_SIZE_LIST => {
+                dims.push(usize::try_from(ty.i32(FIXED_SIZE_LIST_SIZE, 0)?)?);
+                let child = field
+                    .tables(FIELD_CHILDREN)?
+                    .into_iter()
+                    .next()
+                    .ok_or_else(|| Error::Format(format!("list column '{}' has no child", name)))?;
+                return Self::decode_field(name, child, dims);
+            }
+            TYPE_INT => (
+                false,
+                ty.bool(INT_IS_SIGNED, false)?,
+                usize::try_from(ty.i32(INT_BIT_WIDTH, 0)?)?,
+            ),
+            TYPE_FLOATING_POINT => match ty.i16(FLOATING_POINT_PRECISION, 0)? {
+                PRECISION_HALF => (true, true, 16),
+                PRECISION_SINGLE => (true, true, 32),
+                PRECISION_DOUBLE => (true, true, 64),
+                precision => {
+                    return Err(Error::Format(format!(
+                        "invalid floating point precision {} of column '{}'",
+                        precision, name
+                    )))
+                }
+            },
+            type_type => {
+                return Err(Error::Dtype(format!(
+                    "cannot read column '{}' of Arrow type id {}",
+                    name, type_type
+                )))
+            }
+        };
+
+        if !matches!(bit_width, 8 | 16 | 32 | 64) {
+            return Err(Error::Format(format!(
+                "invalid bit width {} of column '{}'",
+                bit_width, name
+            )));
+        }
+        Ok(ElementType {
+            floating_point,
+            signed,
+            bit_width,
+        })
+    }
+
+    fn push_record_batch(&mut self, batch: Table, body: &[u8]) -> Result<()> {
+        if batch.has(RECORD_BATCH_COMPRESSION)? {
+            return Err(Error::Format(
+                "compressed record batches are not supported".into(),
+            ));
+        }
+
+        let n_rows = usize::try_from(batch.i64(RECORD_BATCH_LENGTH, 0)?)?;
+        let mut nodes = batch
+            .structs(RECORD_BATCH_NODES, FIELD_NODE_SIZE)?
+            .into_iter()
+            .map(read_pair);
+        let mut buffers = batch
+            .structs(RECORD_BATCH_BUFFERS, BUFFER_SIZE)?
+            .into_iter()
+            .map(read_pair);
+        let missing = |what: &str| Error::Format(format!("record batch has too few {}", what));
+        let too_large = |name: &str| Error::Format(format!("column '{}' is too large", name));
+
+        for field in &mut self.fields {
+            let mut len = n_rows;
+            for level in 0..=field.dims.len() {
+                let (node_len, null_count) =
+                    nodes.next().ok_or_else(|| missing("field nodes"))??;
+                if null_count != 0 {
+                    return Err(Error::Value(format!(
+                        "column '{}' contains null values",
+                        field.name
+                    )));
+                }
+                if node_len != len {
+                    return Err(Error::Format(format!(
+                        "column '{}' has {} values at depth {}, but expected {}",
+                        field.name, node_len, level, len
+                    )));
+                }
+                buffers.next().ok_or_else(|| missing("buffers"))??;
+                if let Some(&dim) = field.dims.get(level) {
+                    len = len.checked_mul(dim).ok_or_else(|| too_large(&field.name))?;
+                }
+            }
+
+            let (offset, buffer_len) = buffers.next().ok_or_else(|| missing("buffers"))??;
+            let size = len
+                .checked_mul(field.ty.bit_width / 8)
+                .ok_or_else(|| too_large(&field.name))?;
+            let bytes = offset
+                .checked_add(buffer_len.min(size))
+                .and_then(|end| body.get(offset..end))
+                .filter(|bytes| bytes.len() == size)
+                .ok_or_else(|| {
+                    Error::Format(format!(
+                        "data buffer of column '{}' is too short",
+                        field.name
+                    ))
+                })?;
+            field.data.extend_from_slice(bytes);
+        }
+
+        self.len = self
+            .len
+            .checked_add(n_rows)
+            .ok_or_else(|| Error::Format("Arrow table has too many rows".into()))?;
+        Ok(())
+    }
+}
+
+fn read_pair(bytes: &[u8]) -> Result<(usize, usize)> {
+    let a = i64::from_le_bytes(bytes[..8].try_into().unwrap());
+    let b = i64::from_le_bytes(bytes[8..16].try_into().unwrap());
+    Ok((usize::try_from(a)?, usize::try_from(b)?))
+}
+
+fn read_prefix<R>(reader: &mut R) -> Result<Option<[u8; 4]>>
+where
+    R: Read,
+{
+    let mut buf = [0; 4];
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) if filled == 0 => return Ok(None),
+            Ok(0) => return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into()),
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(Some(buf))
+}
+
+fn read_message<R>(reader: &mut R) -> Result<Option<(Vec<u8>, Vec<u8>)>>
+where
+    R: Read,
+{
+    let mut prefix = match read_prefix(reader)? {
+        Some(prefix) => prefix,
+        None => return Ok(None),
+    };
+    if prefix == CONTINUATION {
+        reader.read_exact(&mut prefix)?;
+    }
+    let len = usize::try_from(i32::from_le_bytes(prefix))?;
+    if len == 0 {
+        return Ok(None);
+    }
+
+    let metadata = read_vec(reader, len)?;
+    let body_len = usize::try_from(Table::root(&metadata)?.i64(MESSAGE_BODY_LENGTH, 0)?)?;
+    let body = read_vec(reader, body_len)?;
+    Ok(Some((metadata, body)))
+}
+
+fn message_header(metadata: &[u8]) -> Result<(u8, Table<'_>)> {
+    let message = Table::root(metadata)?;
+    let header = message
+        .table(MESSAGE_HEADER)?
+        .ok_or_else(|| Error::Format("Arrow message has no header".into()))?;
+    Ok((message.u8(MESSAGE_HEADER_TYPE, 0)?, header))
+}
+
+pub fn read_arrow_stream<T, R>(reader: R) -> Result<Vec<(String, Array<T, DynDim>)>>
+where
+    R: Read,
+    T: ArrowElement,
+{
+    ArrowTable::read_stream(reader)?.columns()
+}
+
+pub fn read_arrow_file<T, R>(reader: R) -> Result<Vec<(String, Array<T, DynDim>)>>
+where
+    R: Read,
+    T: ArrowElement,
+{
+    ArrowTable::read_file(reader)?.columns()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        build_message, build_schema, message_header, push_pair, read_arrow_file, read_arrow_stream,
+        read_message, read_pair, write_arrow_file, write_arrow_stream, write_end_of_stream,
+        write_message, ArrowTable, ArrowWriter, Column, ElementType, Table, BLOCK_SIZE,
+        BUFFER_SIZE, FIELD_CHILDREN, FIELD_NAME, FIELD_NODE_SIZE, FIELD_TYPE, FIELD_TYPE_TYPE,
+        FIXED_SIZE_LIST_SIZE, FLOATING_POINT_PRECISION, FOOTER_RECORD_BATCHES, HEADER_RECORD_BATCH,
+        HEADER_SCHEMA, INT_BIT_WIDTH, INT_IS_SIGNED, MAGIC, RECORD_BATCH_BUFFERS,
+        RECORD_BATCH_LENGTH, RECORD_BATCH_NODES, SCHEMA_FIELDS, TYPE_FIXED_SIZE_LIST,
+        TYPE_FLOATING_POINT, TYPE_INT,
+    };
+    use crate::{array, Array, Error, NDArray, NDArrayOwned, Result};
+
+    // Written by arrow-rs 54.3.1 with 8-byte alignment: an int32 column `x` holding [1, 2, 3]
+    // and a `FixedSizeList<float64, 2>` column `y` holding [[0.5, 1.5], [2.5, 3.5], [4.5, 5.5]].
+    const GOLDEN_STREAM: &[u8] = &[
+        0xff, 0xff, 0xff, 0xff, 0xf8, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a,
+        0x00, 0x0c, 0x00, 0x0a, 0x00, 0x09, 0x00, 0x04, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x04, 0x00, 0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x04, 0x00, 0x08,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x90, 0x00, 0x00, 0x00,
+        0x04, 0x00, 0x00, 0x00, 0x88, 0xff, 0xff, 0xff, 0x20, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x60, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x24, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x08, 0x00, 0x04, 0x00, 0x06, 0x00, 0x00, 0x00, 0x02,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x16, 0x00, 0x10, 0x00, 0x0e, 0x00, 0x0f, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x10, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x03, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x08, 0x00,
+        0x06, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x69, 0x74, 0x65, 0x6d, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x79, 0x00, 0x00, 0x00, 0x10, 0x00, 0x14, 0x00, 0x10, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x10, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x20, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x1c, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0c, 0x00, 0x04,
+        0x00, 0x0b, 0x00, 0x08, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x78, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xff, 0xff, 0xff, 0xff, 0xd8, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x0c, 0x00,
+        0x1a, 0x00, 0x18, 0x00, 0x17, 0x00, 0x04, 0x00, 0x08, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x20,
+        0x00, 0x00, 0x00, 0x58, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x04, 0x00, 0x0a, 0x00, 0x18, 0x00, 0x0c, 0x00, 0x08, 0x00, 0x04,
+        0x00, 0x0a, 0x00, 0x00, 0x00, 0x4c, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x03, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xe0, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x3f, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x04, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x40, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x12, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0x40, 0xff, 0xff,
+        0xff, 0xff, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    // The same record batch as `GOLDEN_STREAM`, written by arrow-rs 54.3.1 in the file format.
+    const GOLDEN_FILE: &[u8] = &[
+        0x41, 0x52, 0x52, 0x4f, 0x57, 0x31, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xf8, 0x00, 0x00,
+        0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x0c, 0x00, 0x0a, 0x00, 0x09, 0x00,
+        0x04, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x01, 0x04, 0x00, 0x08,
+        0x00, 0x08, 0x00, 0x00, 0x00, 0x04, 0x00, 0x08, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+        0x02, 0x00, 0x00, 0x00, 0x90, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x88, 0xff, 0xff,
+        0xff, 0x20, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x60, 0x00,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x08,
+        0x00, 0x04, 0x00, 0x06, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x10, 0x00, 0x16, 0x00,
+        0x10, 0x00, 0x0e, 0x00, 0x0f, 0x00, 0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x10, 0x00, 0x00,
+        0x00, 0x18, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x03, 0x18, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x08, 0x00, 0x06, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x69, 0x74, 0x65, 0x6d,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x79, 0x00, 0x00, 0x00, 0x10, 0x00, 0x14,
+        0x00, 0x10, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x10, 0x00,
+        0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x1c,
+        0x00, 0x00, 0x00, 0x08, 0x00, 0x0c, 0x00, 0x04, 0x00, 0x0b, 0x00, 0x08, 0x00, 0x00, 0x00,
+        0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        0x00, 0x78, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xd8, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x1a, 0x00, 0x18, 0x00, 0x17, 0x00, 0x04,
+        0x00, 0x08, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x58, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x04, 0x00, 0x0a,
+        0x00, 0x18, 0x00, 0x0c, 0x00, 0x08, 0x00, 0x04, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x4c, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe0, 0x3f, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0xf8, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x40, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0c, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x40, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x16, 0x40, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0x10,
+        0x00, 0x00, 0x00, 0x0c, 0x00, 0x14, 0x00, 0x12, 0x00, 0x0c, 0x00, 0x08, 0x00, 0x04, 0x00,
+        0x0c, 0x00, 0x00, 0x00, 0xe8, 0x00, 0x00, 0x00, 0x04, 0x01, 0x00, 0x00, 0x10, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x04, 0x00, 0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x04, 0x00, 0x08, 0x00,
+        0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x90, 0x00, 0x00, 0x00, 0x04,
+        0x00, 0x00, 0x00, 0x88, 0xff, 0xff, 0xff, 0x20, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x10, 0x60, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x08, 0x00, 0x04, 0x00, 0x06, 0x00, 0x00, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x10, 0x00, 0x16, 0x00, 0x10, 0x00, 0x0e, 0x00, 0x0f, 0x00, 0x04, 0x00, 0x00,
+        0x00, 0x08, 0x00, 0x10, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x01, 0x03, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x08, 0x00, 0x06,
+        0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x69, 0x74, 0x65, 0x6d, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x79,
+        0x00, 0x00, 0x00, 0x10, 0x00, 0x14, 0x00, 0x10, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x04, 0x00,
+        0x00, 0x00, 0x08, 0x00, 0x10, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x02, 0x1c, 0x00, 0x00, 0x00, 0x08, 0x00, 0x0c, 0x00, 0x04, 0x00,
+        0x0b, 0x00, 0x08, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x78, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+        0x08, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xe0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x58, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x20, 0x01, 0x00, 0x00, 0x41, 0x52, 0x52, 0x4f, 0x57, 0x31,
+    ];
+
+    fn describe_field(field: Table, out: &mut Vec<String>) -> Result<usize> {
+        let ty = field.table(FIELD_TYPE)?.unwrap();
+        let ty = match field.u8(FIELD_TYPE_TYPE, 0)? {
+            TYPE_INT => format!(
+                "int{} signed={}",
+                ty.i32(INT_BIT_WIDTH, 0)?,
+                ty.bool(INT_IS_SIGNED, false)?
+            ),
+            TYPE_FLOATING_POINT => {
+                format!("float precision={}", ty.i16(FLOATING_POINT_PRECISION, 0)?)
+            }
+            TYPE_FIXED_SIZE_LIST => {
+                format!("fixed_size_list size={}", ty.i32(FIXED_SIZE_LIST_SIZE, 0)?)
+            }
+            type_type => format!("type {}", type_type),
+        };
+        out.push(format!(
+            "field {} {}",
+            field.string(FIELD_NAME)?.unwrap_or_default(),
+            ty
+        ));
+        let mut depth = 0;
+        for child in field.tables(FIELD_CHILDREN)? {
+            depth = describe_field(child, out)? + 1;
+        }
+        Ok(depth)
+    }
+
+    // Lists what the format pins down about the messages of a stream: the schema, the field
+    // nodes and the contents of the data buffers. The flatbuffers layout and the validity
+    // bitmaps, which are optional for columns without nulls, differ between writers.
+    fn describe_stream(mut buf: &[u8]) -> Result<Vec<String>> {
+        let mut out = Vec::new();
+        let mut depths = Vec::new();
+        while let Some((metadata, body)) = read_message(&mut buf)? {
+            match message_header(&metadata)? {
+                (HEADER_SCHEMA, schema) => {
+                    for field in schema.tables(SCHEMA_FIELDS)? {
+                        depths.push(describe_field(field, &mut out)?);
+                    }
+                }
+                (HEADER_RECORD_BATCH, batch) => {
+                    out.push(format!(
+                        "record batch of {} rows",
+                        batch.i64(RECORD_BATCH_LENGTH, 0)?
+                    ));
+                    for node in batch.structs(RECORD_BATCH_NODES, FIELD_NODE_SIZE)? {
+                        out.push(format!("node {:?}", read_pair(node)?));
+                    }
+                    let mut buffers = batch
+                        .structs(RECORD_BATCH_BUFFERS, BUFFER_SIZE)?
+                        .into_iter();
+                    for &depth in &depths {
+                        let (offset, len) = read_pair(buffers.nth(depth + 1).unwrap())?;
+                        out.push(format!("data {:?}", &body[offset..offset + len]));
+                    }
+                }
+                (header_type, _) => out.push(format!("message {}", header_type)),
+            }
+        }
+        Ok(out)
+    }
+
+    fn golden_writer() -> Result<ArrowWriter<Vec<u8>>> {
+        let mut arrow = ArrowWriter::new(Vec::new());
+        arrow.add_column("x", &array!([1_i32, 2, 3]))?;
+        arrow.add_column("y", &array!([[0.5_f64, 1.5], [2.5, 3.5], [4.5, 5.5]]))?;
+        Ok(arrow)
+    }
+
+    #[test]
+    fn read_golden() -> Result<()> {
+        for table in [
+            ArrowTable::read_stream(GOLDEN_STREAM)?,
+            ArrowTable::read_file(GOLDEN_FILE)?,
+        ] {
+            let x = table.column::<i32>("x")?;
+            let y = table.column::<f64>("y")?;
+
+            assert_eq!(table.names().collect::<Vec<_>>(), vec!["x", "y"]);
+            assert_eq!(x.shape(), &vec![3]);
+            assert_eq!(x.iter().cloned().collect::<Vec<_>>(), vec![1, 2, 3]);
+            assert_eq!(y.shape(), &vec![3, 2]);
+            assert_eq!(
+                y.iter().cloned().collect::<Vec<_>>(),
+                vec![0.5, 1.5, 2.5, 3.5, 4.5, 5.5]
+            );
+        }
+
+        assert!(matches!(
+            read_arrow_file::<i32, _>(GOLDEN_FILE),
+            Err(Error::Dtype(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_golden() -> Result<()> {
+        let golden = describe_stream(GOLDEN_STREAM)?;
+
+        assert_eq!(
+            golden[..5],
+            [
+                "field x int32 signed=true",
+                "field y fixed_size_list size=2",
+                "field item float precision=2",
+                "record batch of 3 rows",
+                "node (3, 0)",
+            ]
+        );
+        assert_eq!(describe_stream(&GOLDEN_FILE[8..])?, golden);
+        assert_eq!(describe_stream(&golden_writer()?.finish_stream()?)?, golden);
+
+        let file = golden_writer()?.finish_file()?;
+
+        assert_eq!(file[..8], GOLDEN_FILE[..8]);
+        assert_eq!(
+            file[file.len() - MAGIC.len()..],
+            GOLDEN_FILE[GOLDEN_FILE.len() - MAGIC.len()..]
+        );
+        assert_eq!(describe_stream(&file[8..])?, golden);
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_and_read_stream() -> Result<()> {
+        let x = Array::from(vec![1_i32, 2, 3]).into_shape(vec![3_isize])?;
+        let y = Array::from((0..12).collect::<Vec<i32>>()).into_shape(vec![3_isize, 2, 2])?;
+        let mut buf = Vec::new();
+        write_arrow_stream(&mut buf, vec![("x", &x), ("y", &y)])?;
+
+        assert_eq!(buf.len() % 8, 0);
+        assert_eq!(&buf[buf.len() - 8..], &[0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0]);
+
+        let columns = read_arrow_stream::<i32, _>(buf.as_slice())?;
+
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0].0, "x");
+        assert_eq!(columns[0].1, x);
+        assert_eq!(columns[1].0, "y");
+        assert_eq!(columns[1].1, y);
+        assert!(matches!(
+            read_arrow_stream::<u32, _>(buf.as_slice()),
+            Err(Error::Dtype(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_and_read_file() -> Result<()> {
+        let a = array!([[1.5_f64, 2.5], [3.5, 4.5], [5.5, 6.5]]);
+        let mut buf = Vec::new();
+        write_arrow_file(&mut buf, vec![("a", &a)])?;
+
+        assert!(buf.starts_with(b"ARROW1\0\0"));
+        assert!(buf.ends_with(b"ARROW1"));
+
+        let columns = read_arrow_file::<f64, _>(buf.as_slice())?;
+
+        assert_eq!(columns[0].0, "a");
+        assert_eq!(columns[0].1.shape(), &vec![3, 2]);
+        assert_eq!(
+            columns[0].1.iter().cloned().collect::<Vec<_>>(),
+            vec![1.5, 2.5, 3.5, 4.5, 5.5, 6.5]
+        );
+        assert!(matches!(
+            read_arrow_file::<f32, _>(buf.as_slice()),
+            Err(Error::Dtype(_))
+        ));
+        assert!(read_arrow_file::<f64, _>(&buf[..buf.len() - 1]).is_err());
+
+        let t = a.transpose();
+        let mut buf = Vec::new();
+        write_arrow_file(&mut buf, vec![("t", &t)])?;
+        let columns = read_arrow_file::<f64, _>(buf.as_slice())?;
+
+        assert_eq!(
+            columns[0].1.iter().cloned().collect::<Vec<_>>(),
+            vec![1.5, 3.5, 5.5, 2.5, 4.5, 6.5]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_invalid_columns() {
+        let a = array!([1_u8, 2, 3]);
+        let b = array!([1_u8, 2]);
+        let mut buf = Vec::new();
+
+        assert!(matches!(
+            write_arrow_stream(&mut buf, vec![("a", &a), ("b", &b)]),
+            Err(Error::Shape(_))
+        ));
+    }
+
+    #[test]
+    fn read_oversized_column() -> Result<()> {
+        let n_rows = 1 << 40;
+        let columns = [Column {
+            name: "x".into(),
+            shape: vec![n_rows, i32::MAX as usize, i32::MAX as usize],
+            ty: ElementType::of::<u8>(),
+            data: Vec::new(),
+        }];
+        let schema = build_message(HEADER_SCHEMA, 0, |builder| build_schema(builder, &columns));
+        let batch = build_message(HEADER_RECORD_BATCH, 0, |builder| {
+            let mut nodes = Vec::new();
+            let mut buffers = Vec::new();
+            push_pair(&mut nodes, n_rows, 0);
+            push_pair(&mut buffers, 0, 0);
+            let nodes = builder.create_vector_of_structs(&nodes, 1, 8);
+            let buffers = builder.create_vector_of_structs(&buffers, 1, 8);
+            builder.start_table();
+            builder.add_i64(RECORD_BATCH_LENGTH, n_rows as i64);
+            builder.add_offset(RECORD_BATCH_NODES, nodes);
+            builder.add_offset(RECORD_BATCH_BUFFERS, buffers);
+            builder.end_table()
+        });
+        let mut buf = Vec::new();
+        write_message(&mut buf, &schema, &[])?;
+        write_message(&mut buf, &batch, &[])?;
+        write_end_of_stream(&mut buf)?;
+
+        assert!(matches!(
+            read_arrow_stream::<u8, _>(buf.as_slice()),
+            Err(Error::Format(message)) if message.contains("too large")
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_truncated_message() -> Result<()> {
+        let mut buf = vec![0xff; 4];
+        buf.extend_from_slice(&i32::MAX.to_le_bytes());
+
+        assert!(matches!(
+            read_arrow_stream::<u8, _>(buf.as_slice()),
+            Err(Error::Io(_))
+        ));
+
+        let schema = build_message(HEADER_SCHEMA, 1 << 50, |builder| build_schema(builder, &[]));
+        let mut buf = Vec::new();
+        write_message(&mut buf, &schema, &[])?;
+
+        assert!(matches!(
+            read_arrow_stream::<u8, _>(buf.as_slice()),
+            Err(Error::Io(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_out_of_bounds_block() -> Result<()> {
+        let mut buf = Vec::new();
+        write_arrow_file(&mut buf, vec![("a", &array!([1_i64, 2, 3]))])?;
+        let footer_end = buf.len() - 4 - MAGIC.len();
+        let footer_len = i32::from_le_bytes(buf[footer_end..footer_end + 4].try_into().unwrap());
+        let footer = Table::root(&buf[footer_end - footer_len as usize..footer_end])?;
+        let block = footer.structs(FOOTER_RECORD_BATCHES, BLOCK_SIZE)?[0];
+        let body_len = block.as_ptr() as usize - buf.as_ptr() as usize + 16;
+        buf[body_len..body_len + 8].copy_from_slice(&(1_i64 << 50).to_le_bytes());
+
+        assert!(matches!(
+            read_arrow_file::<i64, _>(buf.as_slice()),
+            Err(Error::Format(message)) if message.contains("out of bounds")
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_and_read_mixed_types() -> Result<()> {
+        let x = array!([1.5_f32, 2.5, 3.5]);
+        let ids = array!([[1_u16, 2], [3, 4], [5, 6]]);
+        for file in [false, true] {
+            let mut arrow = ArrowWriter::new(Vec::new());
+            arrow.add_column("x", &x)?;
+            arrow.add_column("ids", &ids)?;
+            let table = if file {
+                ArrowTable::read_file(arrow.finish_file()?.as_slice())?
+            } else {
+                ArrowTable::read_stream(arrow.finish_stream()?.as_slice())?
+            };
+
+            assert_eq!(table.names().collect::<Vec<_>>(), vec!["x", "ids"]);
+            assert_eq!(
+                table
+                    .column::<f32>("x")?
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                vec![1.5, 2.5, 3.5]
+            );
+            assert_eq!(table.column::<u16>("ids")?.shape(), &vec![3, 2]);
+            assert!(matches!(table.column::<u16>("x"), Err(Error::Dtype(_))));
+            assert!(matches!(table.columns::<f32>(), Err(Error::Dtype(_))));
+            assert!(matches!(table.column::<f32>("y"), Err(Error::Value(_))));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,240 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::{mem, ptr, slice};
+
+use num_complex::Complex;
+
+use crate::{
+    storage::Storage, util, Array, ColumnMajor, Dimensionality, NDArray, Order, Result, ShapeError,
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Endian {
+    Big,
+    Little,
+}
+
+impl Endian {
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Self = Self::Big;
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Self = Self::Little;
+}
+
+/// A type that can be copied to and from raw bytes.
+///
+/// # Safety
+///
+/// The type must have no padding, and every bit pattern of its size must be
+/// a valid value.
+pub unsafe trait Pod: Copy + 'static {
+    fn swap_bytes(self) -> Self;
+}
+
+macro_rules! impl_pod_for_int {
+    ($($type:ty),*) => {
+        $(
+            unsafe impl Pod for $type {
+                fn swap_bytes(self) -> Self {
+                    <$type>::swap_bytes(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_pod_for_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+unsafe impl Pod for f32 {
+    fn swap_bytes(self) -> Self {
+        Self::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+unsafe impl Pod for f64 {
+    fn swap_bytes(self) -> Self {
+        Self::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+unsafe impl<T> Pod for Complex<T>
+where
+    T: Pod,
+{
+    fn swap_bytes(self) -> Self {
+        Complex::new(self.re.swap_bytes(), self.im.swap_bytes())
+    }
+}
+
+fn as_bytes<T>(elems: &[T]) -> &[u8]
+where
+    T: Pod,
+{
+    unsafe { slice::from_raw_parts(elems.as_ptr() as *const u8, mem::size_of_val(elems)) }
+}
+
+pub fn to_bytes<O, A>(array: &A, endian: Endian) -> Vec<u8>
+where
+    A: NDArray,
+    <A::Storage as Storage>::Elem: Pod,
+    O: Order,
+{
+    let swap = endian != Endian::NATIVE;
+    let size = mem::size_of::<<A::Storage as Storage>::Elem>();
+    let mut buf = Vec::with_capacity(array.len() * size);
+    let push = |elem: &<A::Storage as Storage>::Elem| {
+        let elem = if swap { elem.swap_bytes() } else { *elem };
+        buf.extend_from_slice(as_bytes(slice::from_ref(&elem)));
+    };
+
+    if O::is_data_contiguous::<A::Dimensionality>(array.shape(), array.strides()) {
+        let elems = unsafe { slice::from_raw_parts(array.as_ptr(), array.len()) };
+        if !swap {
+            return as_bytes(elems).to_vec();
+        }
+        elems.iter().for_each(push);
+    } else if util::type_eq::<O, ColumnMajor>() {
+        array.transpose().iter().for_each(push);
+    } else {
+        array.iter().for_each(push);
+    }
+
+    buf
+}
+
+pub fn from_bytes<T, D, O>(
+    bytes: &[u8],
+    shape: <D as Dimensionality>::Shape,
+    endian: Endian,
+) -> Result<Array<T, D, O>>
+where
+    D: Dimensionality,
+    O: Order,
+    T: Pod,
+{
+    let size = shape
+        .as_ref()
+        .iter()
+        .try_fold(mem::size_of::<T>(), |size, &dim| size.checked_mul(dim));
+    if size != Some(bytes.len()) {
+        return Err(ShapeError::IncompatibleShape(format!(
+            "cannot create array of shape {:?} from {} bytes",
+            shape,
+            bytes.len()
+        ))
+        .into());
+    }
+
+    Array::from_vec_with_order::<O>(shape, vec_from_bytes(bytes, endian))
+}
+
+pub(crate) fn vec_from_bytes<T>(bytes: &[u8], endian: Endian) -> Vec<T>
+where
+    T: Pod,
+{
+    let len = bytes.len() / mem::size_of::<T>();
+    let mut data = Vec::<T>::with_capacity(len);
+    unsafe {
+        ptr::copy_nonoverlapping(
+            bytes.as_ptr(),
+            data.as_mut_ptr() as *mut u8,
+            len * mem::size_of::<T>(),
+        );
+        data.set_len(len);
+    }
+    if endian != Endian::NATIVE {
+        data.iter_mut().for_each(|elem| *elem = elem.swap_bytes());
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use num_complex::Complex;
+
+    use super::{from_bytes, to_bytes, Endian};
+    use crate::{
+        array, s, Array, ColumnMajor, Error, NDArray, NDims, Result, RowMajor, ShapeError,
+    };
+
+    #[test]
+    fn convert_to_bytes_with_endian() {
+        let a = array!([1_u16, 2, 3]);
+
+        assert_eq!(
+            to_bytes::<RowMajor, _>(&a, Endian::Little),
+            vec![1, 0, 2, 0, 3, 0]
+        );
+        assert_eq!(
+            to_bytes::<RowMajor, _>(&a, Endian::Big),
+            vec![0, 1, 0, 2, 0, 3]
+        );
+        assert_eq!(
+            to_bytes::<RowMajor, _>(&array!([1.5_f32]), Endian::Big),
+            1.5_f32.to_be_bytes().to_vec()
+        );
+    }
+
+    #[test]
+    fn convert_to_bytes_with_order() {
+        let a = array!([[1_u8, 2, 3], [4, 5, 6]]);
+
+        assert_eq!(
+            to_bytes::<RowMajor, _>(&a, Endian::NATIVE),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(
+            to_bytes::<ColumnMajor, _>(&a, Endian::NATIVE),
+            vec![1, 4, 2, 5, 3, 6]
+        );
+        assert_eq!(
+            to_bytes::<RowMajor, _>(&a.slice(s!(..;-1, 1..)), Endian::NATIVE),
+            vec![5, 6, 2, 3]
+        );
+        assert_eq!(
+            to_bytes::<ColumnMajor, _>(&a.transpose(), Endian::NATIVE),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+
+    #[test]
+    fn convert_from_bytes() -> Result<()> {
+        let a = array!([[1_i32, 2, 3], [4, 5, 6]]);
+        for endian in [Endian::Big, Endian::Little] {
+            {
+                let bytes = to_bytes::<RowMajor, _>(&a, endian);
+                let b = from_bytes::<i32, NDims<2>, RowMajor>(&bytes, [2, 3], endian)?;
+
+                assert_eq!(b, a);
+            }
+            {
+                let bytes = to_bytes::<ColumnMajor, _>(&a, endian);
+                let b = from_bytes::<i32, NDims<2>, ColumnMajor>(&bytes, [2, 3], endian)?;
+
+                assert_eq!(b.strides(), &[1, 2]);
+                assert_eq!(
+                    b.iter().cloned().collect::<Vec<_>>(),
+                    vec![1, 2, 3, 4, 5, 6]
+                );
+            }
+        }
+
+        let c = Array::from(vec![Complex::new(1_f64, -1.)]);
+        let bytes = to_bytes::<RowMajor, _>(&c, Endian::Big);
+
+        assert_eq!(
+            from_bytes::<_, NDims<1>, RowMajor>(&bytes, [1], Endian::Big)?,
+            c
+        );
+        assert!(from_bytes::<u16, NDims<1>, RowMajor>(&[0; 3], [2], Endian::Little).is_err());
+        assert!(matches!(
+            from_bytes::<u8, NDims<2>, RowMajor>(&[], [1 << 32, 1 << 32], Endian::Little),
+            Err(Error::Shape(ShapeError::IncompatibleShape(_)))
+        ));
+
+        Ok(())
+    }
+}
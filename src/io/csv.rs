@@ -0,0 +1,314 @@
+use std::{
+    fmt::Display,
+    io::{BufRead, BufReader, Read, Write},
+    str::FromStr,
+};
+
+use crate::{
+    array::format_with_precision, print_options, storage::Storage, Array, Error, FormatElement,
+    NDArray, NDims, Result, RowMajor, ShapeError,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MissingValue {
+    Error,
+    Fill(String),
+    SkipRow,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CsvOptions {
+    delimiter: char,
+    skip_header: usize,
+    comment: Option<char>,
+    missing: MissingValue,
+    precision: Option<usize>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            skip_header: 0,
+            comment: None,
+            missing: MissingValue::Error,
+            precision: None,
+        }
+    }
+}
+
+impl CsvOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn comment(mut self, comment: char) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn missing(mut self, missing: MissingValue) -> Self {
+        self.missing = missing;
+        self
+    }
+
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    pub fn skip_header(mut self, lines: usize) -> Self {
+        self.skip_header = lines;
+        self
+    }
+}
+
+pub fn read_csv<T, R>(reader: R, options: &CsvOptions) -> Result<Array<T, NDims<2>>>
+where
+    R: Read,
+    T: Clone + FromStr,
+{
+    let fill = match &options.missing {
+        MissingValue::Fill(value) => Some(parse::<T>(value, None)?),
+        _ => None,
+    };
+
+    let mut data = Vec::new();
+    let mut n_rows = 0;
+    let mut n_cols = None;
+    for (i, line) in BufReader::new(reader)
+        .lines()
+        .enumerate()
+        .skip(options.skip_header)
+    {
+        let line = line?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || matches!(options.comment, Some(c) if trimmed.starts_with(c)) {
+            continue;
+        }
+
+        // Surrounding whitespace is significant when it is the delimiter
+        let fields = line
+            .split(options.delimiter)
+            .map(|field| {
+                if options.delimiter.is_whitespace() {
+                    field
+                } else {
+                    field.trim()
+                }
+            })
+            .collect::<Vec<_>>();
+        match n_cols {
+            Some(n) if n != fields.len() => {
+                return Err(ShapeError::IncompatibleShape(format!(
+                    "line {} has {} columns, but expected {}",
+                    i + 1,
+                    fields.len(),
+                    n
+                ))
+                .into())
+            }
+            _ => n_cols = Some(fields.len()),
+        }
+
+        let mut row = Vec::with_capacity(fields.len());
+        for (j, field) in fields.iter().enumerate() {
+            if !field.is_empty() {
+                row.push(parse(field, Some((i + 1, j + 1)))?);
+                continue;
+            }
+            match (&options.missing, &fill) {
+                (MissingValue::SkipRow, _) => break,
+                (_, Some(fill)) => row.push(fill.clone()),
+                _ => {
+                    return Err(Error::Value(format!(
+                        "missing value at line {}, column {}",
+                        i + 1,
+                        j + 1
+                    )))
+                }
+            }
+        }
+        if row.len() == fields.len() {
+            data.extend(row);
+            n_rows += 1;
+        }
+    }
+
+    Array::from_vec_with_order::<RowMajor>([n_rows, n_cols.unwrap_or(0)], data)
+}
+
+fn parse<T>(field: &str, position: Option<(usize, usize)>) -> Result<T>
+where
+    T: FromStr,
+{
+    field.parse().map_err(|_| {
+        Error::Value(match position {
+            Some((line, column)) => format!(
+                "cannot parse '{}' at line {}, column {}",
+                field, line, column
+            ),
+            None => format!("cannot parse fill value '{}'", field),
+        })
+    })
+}
+
+pub fn write_csv<A, W>(mut writer: W, array: &A, options: &CsvOptions) -> Result<()>
+where
+    A: NDArray,
+    <A::Storage as Storage>::Elem: Display + FormatElement,
+    W: Write,
+{
+    let n_cols = match array.shape().as_ref() {
+        [_] => 1,
+        [_, n_cols] => *n_cols,
+        shape => {
+            return Err(ShapeError::IncompatibleDimension(format!(
+                "cannot write {}-dimensional array as CSV",
+                shape.len()
+            ))
+            .into())
+        }
+    };
+
+    let precision = options.precision.or_else(|| print_options().precision);
+    let mut line = String::new();
+    for (i, elem) in array.iter().enumerate() {
+        if i % n_cols != 0 {
+            line.push(options.delimiter);
+        }
+        line.push_str(&format_with_precision(elem, precision));
+        if (i + 1) % n_cols == 0 {
+            line.push('\n');
+            writer.write_all(line.as_bytes())?;
+            line.clear();
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_csv, write_csv, CsvOptions, MissingValue};
+    use crate::{array, ArrayBase, Error, NDArray, Result};
+
+    #[test]
+    fn read_with_options() -> Result<()> {
+        let text = "x;y;z\n# comment\n1;2;3\n\n4; ;6\n7;8;9\n";
+        {
+            let options = CsvOptions::new()
+                .delimiter(';')
+                .skip_header(1)
+                .comment('#')
+                .missing(MissingValue::Fill("0".into()));
+            let a = read_csv::<i32, _>(text.as_bytes(), &options)?;
+
+            assert_eq!(a, array!([[1, 2, 3], [4, 0, 6], [7, 8, 9]]));
+        }
+        {
+            let options = CsvOptions::new()
+                .delimiter(';')
+                .skip_header(1)
+                .comment('#')
+                .missing(MissingValue::SkipRow);
+            let a = read_csv::<f64, _>(text.as_bytes(), &options)?;
+
+            assert_eq!(a, array!([[1., 2., 3.], [7., 8., 9.]]));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_whitespace_delimited() -> Result<()> {
+        let options = CsvOptions::new()
+            .delimiter('\t')
+            .missing(MissingValue::Fill("0".into()));
+        let a = read_csv::<i32, _>("1\t\t3\n\t5\t6\n".as_bytes(), &options)?;
+
+        assert_eq!(a, array!([[1, 0, 3], [0, 5, 6]]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_invalid_text() {
+        let options = CsvOptions::new();
+
+        assert!(matches!(
+            read_csv::<i32, _>("1,2\n3,\n".as_bytes(), &options),
+            Err(Error::Value(_))
+        ));
+        assert!(matches!(
+            read_csv::<i32, _>("1,2\n3,x\n".as_bytes(), &options),
+            Err(Error::Value(_))
+        ));
+        assert!(matches!(
+            read_csv::<i32, _>("1,2\n3,4,5\n".as_bytes(), &options),
+            Err(Error::Shape(_))
+        ));
+    }
+
+    #[test]
+    fn write_and_read() -> Result<()> {
+        let a = array!([[1.25_f64, 2.5], [3., 4.75]]);
+        {
+            let mut buf = Vec::new();
+            write_csv(&mut buf, &a, &CsvOptions::new())?;
+
+            assert_eq!(
+                String::from_utf8(buf.clone()).unwrap(),
+                "1.25,2.5\n3,4.75\n"
+            );
+            assert_eq!(read_csv::<f64, _>(buf.as_slice(), &CsvOptions::new())?, a);
+        }
+        {
+            let mut buf = Vec::new();
+            write_csv(
+                &mut buf,
+                &a,
+                &CsvOptions::new().delimiter('\t').precision(1),
+            )?;
+
+            assert_eq!(String::from_utf8(buf).unwrap(), "1.2\t2.5\n3.0\t4.8\n");
+        }
+        {
+            let mut buf = Vec::new();
+            write_csv(
+                &mut buf,
+                &ArrayBase::from(vec![String::from("abc"), String::from("d")]),
+                &CsvOptions::new().precision(1),
+            )?;
+
+            assert_eq!(String::from_utf8(buf).unwrap(), "abc\nd\n");
+        }
+        {
+            let mut buf = Vec::new();
+            write_csv(&mut buf, &array!([1, 2, 3]), &CsvOptions::new())?;
+
+            assert_eq!(String::from_utf8(buf).unwrap(), "1\n2\n3\n");
+        }
+        {
+            let mut buf = Vec::new();
+
+            assert!(write_csv(&mut buf, &array!([[[1]]]), &CsvOptions::new()).is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_empty_text() -> Result<()> {
+        let a = read_csv::<u8, _>("# nothing\n".as_bytes(), &CsvOptions::new().comment('#'))?;
+
+        assert_eq!(a.shape(), &[0, 0]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{Error, Result};
+
+const VTABLE_HEADER_LEN: usize = 4;
+
+pub(crate) struct Builder {
+    buf: Vec<u8>,
+    min_align: usize,
+    fields: Vec<(u16, usize)>,
+    table_start: usize,
+}
+
+impl Builder {
+    pub(crate) fn new() -> Self {
+        Self {
+            buf: Vec::new(),
+            min_align: 1,
+            fields: Vec::new(),
+            table_start: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    fn prepend(&mut self, bytes: &[u8]) {
+        self.buf.splice(0..0, bytes.iter().cloned());
+    }
+
+    fn align(&mut self, size: usize, align: usize) {
+        self.min_align = self.min_align.max(align);
+        let padding = (align - (self.len() + size) % align) % align;
+        self.prepend(&vec![0; padding]);
+    }
+
+    fn prepend_uoffset(&mut self, target: usize) {
+        self.align(4, 4);
+        let value = (self.len() + 4 - target) as u32;
+        self.prepend(&value.to_le_bytes());
+    }
+
+    pub(crate) fn create_string(&mut self, s: &str) -> usize {
+        self.align(s.len() + 1, 4);
+        self.prepend(&[0]);
+        self.prepend(s.as_bytes());
+        self.prepend(&(s.len() as u32).to_le_bytes());
+        self.len()
+    }
+
+    pub(crate) fn create_vector_of_offsets(&mut self, targets: &[usize]) -> usize {
+        self.align(targets.len() * 4, 4);
+        for &target in targets.iter().rev() {
+            self.prepend_uoffset(target);
+        }
+        self.prepend(&(targets.len() as u32).to_le_bytes());
+        self.len()
+    }
+
+    pub(crate) fn create_vector_of_structs(
+        &mut self,
+        data: &[u8],
+        len: usize,
+        align: usize,
+    ) -> usize {
+        self.align(data.len(), align.max(4));
+        self.prepend(data);
+        self.prepend(&(len as u32).to_le_bytes());
+        self.len()
+    }
+
+    pub(crate) fn start_table(&mut self) {
+        self.fields.clear();
+        self.table_start = self.len();
+    }
+
+    fn add_scalar(&mut self, slot: u16, bytes: &[u8]) {
+        self.align(bytes.len(), bytes.len());
+        self.prepend(bytes);
+        self.fields.push((slot, self.len()));
+    }
+
+    pub(crate) fn add_bool(&mut self, slot: u16, value: bool) {
+        self.add_scalar(slot, &[value as u8]);
+    }
+
+    pub(crate) fn add_i16(&mut self, slot: u16, value: i16) {
+        self.add_scalar(slot, &value.to_le_bytes());
+    }
+
+    pub(crate) fn add_i32(&mut self, slot: u16, value: i32) {
+        self.add_scalar(slot, &value.to_le_bytes());
+    }
+
+    pub(crate) fn add_i64(&mut self, slot: u16, value: i64) {
+        self.add_scalar(slot, &value.to_le_bytes());
+    }
+
+    pub(crate) fn add_u8(&mut self, slot: u16, value: u8) {
+        self.add_scalar(slot, &[value]);
+    }
+
+    pub(crate) fn add_offset(&mut self, slot: u16, target: usize) {
+        self.prepend_uoffset(target);
+        self.fields.push((slot, self.len()));
+    }
+
+    pub(crate) fn end_table(&mut self) -> usize {
+        self.align(4, 4);
+        self.prepend(&[0; 4]);
+        let table = self.len();
+
+        let n_slots = self
+            .fields
+            .iter()
+            .map(|&(slot, _)| slot as usize + 1)
+            .max()
+            .unwrap_or(0);
+        let mut vtable = vec![0_u8; VTABLE_HEADER_LEN + n_slots * 2];
+        let vtable_len = vtable.len() as u16;
+        vtable[0..2].copy_from_slice(&vtable_len.to_le_bytes());
+        vtable[2..4].copy_from_slice(&((table - self.table_start) as u16).to_le_bytes());
+        for &(slot, pos) in &self.fields {
+            let i = VTABLE_HEADER_LEN + slot as usize * 2;
+            vtable[i..i + 2].copy_from_slice(&((table - pos) as u16).to_le_bytes());
+        }
+        self.align(vtable.len(), 2);
+        self.prepend(&vtable);
+
+        let soffset = (self.len() - table) as i32;
+        let i = self.len() - table;
+        self.buf[i..i + 4].copy_from_slice(&soffset.to_le_bytes());
+        self.fields.clear();
+        table
+    }
+
+    pub(crate) fn finish(mut self, root: usize) -> Vec<u8> {
+        let min_align = self.min_align.max(4);
+        self.align(4, min_align);
+        self.prepend_uoffset(root);
+        self.buf
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct Table<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    vtable: usize,
+}
+
+fn read<const N: usize>(buf: &[u8], pos: usize) -> Result<[u8; N]> {
+    buf.get(pos..pos + N)
+        .map(|bytes| bytes.try_into().unwrap())
+        .ok_or_else(|| Error::Format("flatbuffer offset is out of bounds".into()))
+}
+
+fn follow(buf: &[u8], pos: usize) -> Result<usize> {
+    Ok(pos + u32::from_le_bytes(read(buf, pos)?) as usize)
+}
+
+impl<'a> Table<'a> {
+    pub(crate) fn root(buf: &'a [u8]) -> Result<Self> {
+        Self::at(buf, follow(buf, 0)?)
+    }
+
+    fn at(buf: &'a [u8], pos: usize) -> Result<Self> {
+        let soffset = i32::from_le_bytes(read(buf, pos)?) as isize;
+        let vtable = pos as isize - soffset;
+        if vtable < 0 {
+            return Err(Error::Format("flatbuffer vtable is out of bounds".into()));
+        }
+        Ok(Self {
+            buf,
+            pos,
+            vtable: vtable as usize,
+        })
+    }
+
+    fn field(&self, slot: u16) -> Result<Option<usize>> {
+        let vtable_len = u16::from_le_bytes(read(self.buf, self.vtable)?) as usize;
+        let i = VTABLE_HEADER_LEN + slot as usize * 2;
+        if i + 2 > vtable_len {
+            return Ok(None);
+        }
+        match u16::from_le_bytes(read(self.buf, self.vtable + i)?) {
+            0 => Ok(None),
+            offset => Ok(Some(self.pos + offset as usize)),
+        }
+    }
+
+    pub(crate) fn bool(&self, slot: u16, default: bool) -> Result<bool> {
+        Ok(self.u8(slot, default as u8)? != 0)
+    }
+
+    pub(crate) fn i16(&self, slot: u16, default: i16) -> Result<i16> {
+        match self.field(slot)? {
+            Some(pos) => Ok(i16::from_le_bytes(read(self.buf, pos)?)),
+            None => Ok(default),
+        }
+    }
+
+    pub(crate) fn i32(&self, slot: u16, default: i32) -> Result<i32> {
+        match self.field(slot)? {
+            Some(pos) => Ok(i32::from_le_bytes(read(self.buf, pos)?)),
+            None => Ok(default),
+        }
+    }
+
+    pub(crate) fn i64(&self, slot: u16, default: i64) -> Result<i64> {
+        match self.field(slot)? {
+            Some(pos) => Ok(i64::from_le_bytes(read(self.buf, pos)?)),
+            None => Ok(default),
+        }
+    }
+
+    pub(crate) fn u8(&self, slot: u16, default: u8) -> Result<u8> {
+        match self.field(slot)? {
+            Some(pos) => Ok(read::<1>(self.buf, pos)?[0]),
+            None => Ok(default),
+        }
+    }
+
+    pub(crate) fn has(&self, slot: u16) -> Result<bool> {
+        Ok(self.field(slot)?.is_some())
+    }
+
+    pub(crate) fn string(&self, slot: u16) -> Result<Option<&'a str>> {
+        match self.field(slot)? {
+            Some(pos) => {
+                let start = follow(self.buf, pos)?;
+                let len = u32::from_le_bytes(read(self.buf, start)?) as usize;
+                self.buf
+                    .get(start + 4..start + 4 + len)
+                    .and_then(|bytes| core::str::from_utf8(bytes).ok())
+                    .map(Some)
+                    .ok_or_else(|| Error::Format("invalid flatbuffer string".into()))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn table(&self, slot: u16) -> Result<Option<Table<'a>>> {
+        match self.field(slot)? {
+            Some(pos) => Ok(Some(Self::at(self.buf, follow(self.buf, pos)?)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn tables(&self, slot: u16) -> Result<Vec<Table<'a>>> {
+        match self.field(slot)? {
+            Some(pos) => {
+                let start = follow(self.buf, pos)?;
+                let len = u32::from_le_bytes(read(self.buf, start)?) as usize;
+                (0..len)
+                    .map(|i| {
+                        let elem = start + 4 + i * 4;
+                        Self::at(self.buf, follow(self.buf, elem)?)
+                    })
+                    .collect()
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    pub(crate) fn structs(&self, slot: u16, size: usize) -> Result<Vec<&'a [u8]>> {
+        match self.field(slot)? {
+            Some(pos) => {
+                let start = follow(self.buf, pos)?;
+                let len = u32::from_le_bytes(read(self.buf, start)?) as usize;
+                (0..len)
+                    .map(|i| {
+                        let elem = start + 4 + i * size;
+                        self.buf.get(elem..elem + size).ok_or_else(|| {
+                            Error::Format("flatbuffer struct is out of bounds".into())
+                        })
+                    })
+                    .collect()
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Builder, Table};
+    use crate::Result;
+
+    #[test]
+    fn build_and_read_tables() -> Result<()> {
+        let mut builder = Builder::new();
+        let name = builder.create_string("column");
+        builder.start_table();
+        builder.add_i32(0, 64);
+        builder.add_bool(1, true);
+        let child = builder.end_table();
+        let children = builder.create_vector_of_offsets(&[child, child]);
+        let structs = builder.create_vector_of_structs(
+            &[1_i64, 2, 3, 4]
+                .iter()
+                .flat_map(|x| x.to_le_bytes())
+                .collect::<Vec<_>>(),
+            2,
+            8,
+        );
+        builder.start_table();
+        builder.add_offset(0, name);
+        builder.add_i64(1, -5);
+        builder.add_offset(2, children);
+        builder.add_offset(3, structs);
+        builder.add_u8(5, 7);
+        let root = builder.end_table();
+        let buf = builder.finish(root);
+
+        let table = Table::root(&buf)?;
+
+        assert_eq!(table.string(0)?, Some("column"));
+        assert_eq!(table.i64(1, 0)?, -5);
+        assert_eq!(table.u8(4, 9)?, 9);
+        assert_eq!(table.u8(5, 9)?, 7);
+        assert!(!table.has(6)?);
+
+        let children = table.tables(2)?;
+
+        assert_eq!(children.len(), 2);
+        assert_eq!(children[1].i32(0, 0)?, 64);
+        assert!(children[1].bool(1, false)?);
+
+        let structs = table.structs(3, 16)?;
+
+        assert_eq!(structs.len(), 2);
+        assert_eq!(structs[1][8], 4);
+        assert_eq!(structs[0].as_ptr() as usize % 8, buf.as_ptr() as usize % 8);
+
+        Ok(())
+    }
+}
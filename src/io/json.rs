@@ -0,0 +1,332 @@
+use crate::{Error, Result};
+
+const MAX_DEPTH: usize = 128;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    pub(crate) fn parse(s: &str) -> Result<Self> {
+        let mut parser = JsonParser {
+            bytes: s.as_bytes(),
+            pos: 0,
+            depth: 0,
+        };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.pos != parser.bytes.len() {
+            return Err(parser.error("trailing characters"));
+        }
+        Ok(value)
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[JsonValue]> {
+        match self {
+            Self::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_object(&self) -> Option<&[(String, JsonValue)]> {
+        match self {
+            Self::Object(members) => Some(members),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_usize(&self) -> Option<usize> {
+        match self {
+            Self::Number(n) => n.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Option<&JsonValue> {
+        self.as_object()?
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+}
+
+pub(crate) fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+struct JsonParser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    depth: usize,
+}
+
+impl JsonParser<'_> {
+    fn error(&self, what: &str) -> Error {
+        Error::Format(format!("{} at position {} of JSON", what, self.pos))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn consume(&mut self, c: u8) -> bool {
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<()> {
+        if self.consume(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", c as char)))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<JsonValue> {
+        self.skip_whitespace();
+        match self.bytes.get(self.pos) {
+            Some(&c @ b'{') | Some(&c @ b'[') => {
+                if self.depth == MAX_DEPTH {
+                    return Err(self.error("JSON nesting is too deep"));
+                }
+                self.depth += 1;
+                let value = if c == b'{' {
+                    self.parse_object()
+                } else {
+                    self.parse_array()
+                };
+                self.depth -= 1;
+                value
+            }
+            Some(b'"') => self.parse_string().map(JsonValue::String),
+            Some(b'-') | Some(b'0'..=b'9') => self.parse_number(),
+            _ => self.parse_literal(),
+        }
+    }
+
+    fn parse_literal(&mut self) -> Result<JsonValue> {
+        let rest = &self.bytes[self.pos..];
+        for (literal, value) in [
+            (&b"null"[..], JsonValue::Null),
+            (&b"true"[..], JsonValue::Bool(true)),
+            (&b"false"[..], JsonValue::Bool(false)),
+        ] {
+            if rest.starts_with(literal) {
+                self.pos += literal.len();
+                return Ok(value);
+            }
+        }
+        Err(self.error("unexpected token"))
+    }
+
+    fn parse_number(&mut self) -> Result<JsonValue> {
+        let start = self.pos;
+        while self.pos < self.bytes.len()
+            && matches!(
+                self.bytes[self.pos],
+                b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9'
+            )
+        {
+            self.pos += 1;
+        }
+        let number = String::from_utf8_lossy(&self.bytes[start..self.pos]).into_owned();
+        Ok(JsonValue::Number(number))
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        self.expect(b'"')?;
+        let mut out = Vec::new();
+        loop {
+            match self.bytes.get(self.pos) {
+                None => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    let c = match self.bytes.get(self.pos + 1) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            let mut code = self
+                                .parse_hex(self.pos + 2)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?;
+                            self.pos += 4;
+                            if (0xd800..0xdc00).contains(&code)
+                                && self.bytes.get(self.pos + 2..self.pos + 4) == Some(&b"\\u"[..])
+                            {
+                                if let Some(low @ 0xdc00..=0xdfff) = self.parse_hex(self.pos + 4) {
+                                    code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                                    self.pos += 6;
+                                }
+                            }
+                            char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    self.pos += 2;
+                    out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                Some(&c) => {
+                    self.pos += 1;
+                    out.push(c);
+                }
+            }
+        }
+        String::from_utf8(out).map_err(|_| self.error("invalid UTF-8 in string"))
+    }
+
+    fn parse_hex(&self, pos: usize) -> Option<u32> {
+        self.bytes
+            .get(pos..pos + 4)
+            .and_then(|hex| core::str::from_utf8(hex).ok())
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+    }
+
+    fn parse_array(&mut self) -> Result<JsonValue> {
+        let mut values = Vec::new();
+        self.expect(b'[')?;
+        if self.consume(b']') {
+            return Ok(JsonValue::Array(values));
+        }
+        loop {
+            values.push(self.parse_value()?);
+            if !self.consume(b',') {
+                self.expect(b']')?;
+                return Ok(JsonValue::Array(values));
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<JsonValue> {
+        let mut members = Vec::new();
+        self.expect(b'{')?;
+        if self.consume(b'}') {
+            return Ok(JsonValue::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.expect(b':')?;
+            members.push((key, self.parse_value()?));
+            if !self.consume(b',') {
+                self.expect(b'}')?;
+                return Ok(JsonValue::Object(members));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape, JsonValue};
+    use crate::Result;
+
+    #[test]
+    fn parse_json() -> Result<()> {
+        let value = JsonValue::parse(
+            r#" {"a": {"dtype": "F32", "shape": [2, 3], "data_offsets": [0, 24]},
+                 "b\"": [true, false, null, -1.5e3], "c": {}} "#,
+        )?;
+
+        assert_eq!(
+            value.get("a").and_then(|a| a.get("dtype")),
+            Some(&JsonValue::String("F32".into()))
+        );
+        assert_eq!(
+            value
+                .get("a")
+                .and_then(|a| a.get("shape"))
+                .and_then(JsonValue::as_array)
+                .map(|shape| shape
+                    .iter()
+                    .filter_map(JsonValue::as_usize)
+                    .collect::<Vec<_>>()),
+            Some(vec![2, 3])
+        );
+        assert_eq!(
+            value
+                .get("b\"")
+                .and_then(JsonValue::as_array)
+                .map(<[_]>::len),
+            Some(4)
+        );
+        assert_eq!(value.get("c"), Some(&JsonValue::Object(vec![])));
+        assert!(JsonValue::parse("{\"a\": 1,}").is_err());
+        assert!(JsonValue::parse("[1] 2").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_nested_json() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+
+        assert!(JsonValue::parse(&nested(128)).is_ok());
+        assert!(JsonValue::parse(&nested(129)).is_err());
+        assert!(JsonValue::parse(&"[".repeat(100_000)).is_err());
+    }
+
+    #[test]
+    fn parse_surrogate_pairs() -> Result<()> {
+        assert_eq!(
+            JsonValue::parse(r#""\ud83d\ude00 \u00e9""#)?,
+            JsonValue::String("\u{1f600} \u{e9}".into())
+        );
+        assert_eq!(
+            JsonValue::parse(r#""\ud83d \ude00""#)?,
+            JsonValue::String("\u{fffd} \u{fffd}".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn escape_string() -> Result<()> {
+        let s = "a\"b\\c\nd\u{1}";
+
+        assert_eq!(JsonValue::parse(&escape(s))?, JsonValue::String(s.into()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(feature = "std")]
+mod arrow;
+mod bytes;
+#[cfg(feature = "std")]
+mod csv;
+#[cfg(feature = "std")]
+mod flatbuffers;
+#[cfg(feature = "std")]
+mod json;
+#[cfg(feature = "std")]
+mod npy;
+#[cfg(feature = "npz")]
+mod npz;
+#[cfg(feature = "std")]
+mod safetensors;
+
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+#[cfg(feature = "std")]
+pub use arrow::{
+    read_arrow_file, read_arrow_stream, write_arrow_file, write_arrow_stream, ArrowElement,
+    ArrowTable, ArrowWriter,
+};
+pub use bytes::{from_bytes, to_bytes, Endian, Pod};
+#[cfg(feature = "std")]
+pub use csv::{read_csv, write_csv, CsvOptions, MissingValue};
+#[cfg(feature = "std")]
+pub use npy::{read_npy, write_npy, NpyElement};
+#[cfg(feature = "npz")]
+pub use npz::{read_npz, write_npz, write_npz_compressed, NpzReader, NpzWriter};
+#[cfg(feature = "std")]
+pub use safetensors::{write_safetensors, SafeTensors, SafeTensorsElement, SafeTensorsWriter};
+
+/// Reads exactly `len` bytes, growing the buffer as data arrives rather than
+/// allocating `len` bytes up front, since `len` usually comes from an
+/// untrusted header.
+#[cfg(feature = "std")]
+fn read_vec<R>(reader: &mut R, len: usize) -> io::Result<Vec<u8>>
+where
+    R: Read,
+{
+    let mut buf = Vec::new();
+    reader.take(len as u64).read_to_end(&mut buf)?;
+    if buf.len() == len {
+        Ok(buf)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "failed to fill whole buffer",
+        ))
+    }
+}
@@ -0,0 +1,625 @@
+use std::{
+    any,
+    io::{Read, Write},
+};
+
+use num_complex::Complex;
+
+use super::read_vec;
+use crate::{
+    storage::Storage, util, Array, ColumnMajor, Dimensionality, Error, NDArray, Order, Result,
+    RowMajor,
+};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+const HEADER_ALIGNMENT: usize = 64;
+
+pub trait NpyElement: Clone {
+    const DESCR: &'static str;
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self;
+    fn write_bytes(&self, buf: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_element {
+    ($type:ty, $descr:expr) => {
+        impl NpyElement for $type {
+            const DESCR: &'static str = $descr;
+
+            fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let bytes = bytes.try_into().unwrap();
+                if big_endian {
+                    <$type>::from_be_bytes(bytes)
+                } else {
+                    <$type>::from_le_bytes(bytes)
+                }
+            }
+
+            fn write_bytes(&self, buf: &mut Vec<u8>) {
+                buf.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+impl_npy_element!(u8, "u1");
+impl_npy_element!(u16, "u2");
+impl_npy_element!(u32, "u4");
+impl_npy_element!(u64, "u8");
+impl_npy_element!(i8, "i1");
+impl_npy_element!(i16, "i2");
+impl_npy_element!(i32, "i4");
+impl_npy_element!(i64, "i8");
+impl_npy_element!(f32, "f4");
+impl_npy_element!(f64, "f8");
+
+impl NpyElement for bool {
+    const DESCR: &'static str = "b1";
+
+    fn from_bytes(bytes: &[u8], _: bool) -> Self {
+        bytes[0] != 0
+    }
+
+    fn write_bytes(&self, buf: &mut Vec<u8>) {
+        buf.push(*self as u8);
+    }
+}
+
+macro_rules! impl_npy_element_for_complex {
+    ($type:ty, $descr:expr) => {
+        impl NpyElement for Complex<$type> {
+            const DESCR: &'static str = $descr;
+
+            fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let (re, im) = bytes.split_at(bytes.len() / 2);
+                Complex::new(
+                    <$type>::from_bytes(re, big_endian),
+                    <$type>::from_bytes(im, big_endian),
+                )
+            }
+
+            fn write_bytes(&self, buf: &mut Vec<u8>) {
+                self.re.write_bytes(buf);
+                self.im.write_bytes(buf);
+            }
+        }
+    };
+}
+
+impl_npy_element_for_complex!(f32, "c8");
+impl_npy_element_for_complex!(f64, "c16");
+
+#[derive(Debug, PartialEq)]
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+impl Header {
+    fn parse(s: &str) -> Result<Self> {
+        let mut parser = HeaderParser {
+            bytes: s.as_bytes(),
+            pos: 0,
+        };
+        let mut descr = None;
+        let mut fortran_order = None;
+        let mut shape = None;
+
+        parser.expect(b'{')?;
+        loop {
+            if parser.consume(b'}') {
+                break;
+            }
+            let key = parser.parse_str()?;
+            parser.expect(b':')?;
+            match key.as_str() {
+                "descr" => descr = Some(parser.parse_str()?),
+                "fortran_order" => fortran_order = Some(parser.parse_bool()?),
+                "shape" => shape = Some(parser.parse_tuple()?),
+                _ => {
+                    return Err(Error::Format(format!(
+                        "unexpected key '{}' in npy header",
+                        key
+                    )))
+                }
+            }
+            if !parser.consume(b',') {
+                parser.expect(b'}')?;
+                break;
+            }
+        }
+
+        match (descr, fortran_order, shape) {
+            (Some(descr), Some(fortran_order), Some(shape)) => Ok(Self {
+                descr,
+                fortran_order,
+                shape,
+            }),
+            _ => Err(Error::Format(
+                "npy header must have 'descr', 'fortran_order' and 'shape'".into(),
+            )),
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let shape = match self.shape.as_slice() {
+            [dim] => format!("({},)", dim),
+            shape => format!(
+                "({})",
+                shape
+                    .iter()
+                    .map(|dim| dim.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        let mut dict = format!(
+            "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+            self.descr,
+            if self.fortran_order { "True" } else { "False" },
+            shape
+        );
+
+        let unpadded_len = MAGIC.len() + 2 + 2 + dict.len() + 1;
+        let (major, len_bytes) = if unpadded_len + HEADER_ALIGNMENT <= u16::MAX as usize {
+            (1, 2)
+        } else {
+            (2, 4)
+        };
+        let prefix_len = MAGIC.len() + 2 + len_bytes;
+        let padding = (HEADER_ALIGNMENT - (prefix_len + dict.len() + 1) % HEADER_ALIGNMENT)
+            % HEADER_ALIGNMENT;
+        dict.push_str(&" ".repeat(padding));
+        dict.push('\n');
+
+        let mut out = Vec::with_capacity(prefix_len + dict.len());
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&[major, 0]);
+        if major == 1 {
+            out.extend_from_slice(&(dict.len() as u16).to_le_bytes());
+        } else {
+            out.extend_from_slice(&(dict.len() as u32).to_le_bytes());
+        }
+        out.extend_from_slice(dict.as_bytes());
+        out
+    }
+}
+
+struct HeaderParser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl HeaderParser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+    }
+
+    fn consume(&mut self, c: u8) -> bool {
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<()> {
+        if self.consume(c) {
+            Ok(())
+        } else {
+            Err(Error::Format(format!(
+                "expected '{}' at position {} of npy header",
+                c as char, self.pos
+            )))
+        }
+    }
+
+    fn parse_bool(&mut self) -> Result<bool> {
+        self.skip_whitespace();
+        let rest = &self.bytes[self.pos..];
+        if rest.starts_with(b"True") {
+            self.pos += 4;
+            Ok(true)
+        } else if rest.starts_with(b"False") {
+            self.pos += 5;
+            Ok(false)
+        } else {
+            Err(Error::Format(format!(
+                "expected boolean at position {} of npy header",
+                self.pos
+            )))
+        }
+    }
+
+    fn parse_str(&mut self) -> Result<String> {
+        self.skip_whitespace();
+        let quote = match self.bytes.get(self.pos) {
+            Some(&c) if c == b'\'' || c == b'"' => c,
+            _ => {
+                return Err(Error::Format(format!(
+                    "expected string at position {} of npy header",
+                    self.pos
+                )))
+            }
+        };
+        let start = self.pos + 1;
+        let len = self.bytes[start..]
+            .iter()
+            .position(|&c| c == quote)
+            .ok_or_else(|| Error::Format("unterminated string in npy header".into()))?;
+        self.pos = start + len + 1;
+        Ok(String::from_utf8_lossy(&self.bytes[start..start + len]).into_owned())
+    }
+
+    fn parse_tuple(&mut self) -> Result<Vec<usize>> {
+        let mut values = Vec::new();
+        self.expect(b'(')?;
+        loop {
+            if self.consume(b')') {
+                break;
+            }
+            self.skip_whitespace();
+            let start = self.pos;
+            while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_digit() {
+                self.pos += 1;
+            }
+            let value = std::str::from_utf8(&self.bytes[start..self.pos])
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| {
+                    Error::Format(format!(
+                        "expected integer at position {} of npy header",
+                        start
+                    ))
+                })?;
+            values.push(value);
+            if !self.consume(b',') {
+                self.expect(b')')?;
+                break;
+            }
+        }
+        Ok(values)
+    }
+}
+
+pub fn read_npy<T, D, O, R>(mut reader: R) -> Result<Array<T, D, O>>
+where
+    D: Dimensionality,
+    O: Order,
+    R: Read,
+    T: NpyElement,
+{
+    let mut preamble = [0_u8; 8];
+    reader.read_exact(&mut preamble)?;
+    if &preamble[..MAGIC.len()] != MAGIC {
+        return Err(Error::Format("missing npy magic string".into()));
+    }
+
+    let major = preamble[6];
+    let header_len = match major {
+        1 => {
+            let mut buf = [0_u8; 2];
+            reader.read_exact(&mut buf)?;
+            u16::from_le_bytes(buf) as usize
+        }
+        2 | 3 => {
+            let mut buf = [0_u8; 4];
+            reader.read_exact(&mut buf)?;
+            u32::from_le_bytes(buf) as usize
+        }
+        _ => {
+            return Err(Error::Format(format!(
+                "unsupported npy format version {}.{}",
+                major, preamble[7]
+            )))
+        }
+    };
+    let buf = read_vec(&mut reader, header_len)?;
+    let header = if major == 3 {
+        String::from_utf8(buf).map_err(|_| Error::Format("npy header is not valid UTF-8".into()))?
+    } else {
+        buf.iter().map(|&c| c as char).collect()
+    };
+    let header = Header::parse(&header)?;
+
+    let descr = header.descr.as_str();
+    let (big_endian, dtype) = match descr.chars().next() {
+        Some('<') | Some('|') => (false, &descr[1..]),
+        Some('>') => (true, &descr[1..]),
+        Some('=') => (cfg!(target_endian = "big"), &descr[1..]),
+        _ => (false, descr),
+    };
+    if dtype != T::DESCR {
+        return Err(Error::Dtype(format!(
+            "cannot read npy array of dtype '{}' as {}",
+            header.descr,
+            any::type_name::<T>()
+        )));
+    }
+    let elem_size = dtype[1..]
+        .parse::<usize>()
+        .map_err(|_| Error::Format(format!("invalid dtype '{}'", header.descr)))?;
+
+    let shape = D::shape_from_slice(&header.shape)?;
+    let len = header
+        .shape
+        .iter()
+        .try_fold(elem_size, |len, &dim| len.checked_mul(dim))
+        .ok_or_else(|| Error::Format(format!("npy shape {:?} is too large", header.shape)))?;
+    let buf = read_vec(&mut reader, len)?;
+    let data = buf
+        .chunks_exact(elem_size)
+        .map(|bytes| T::from_bytes(bytes, big_endian))
+        .collect();
+
+    if header.fortran_order {
+        Array::from_vec_with_order::<ColumnMajor>(shape, data)
+    } else {
+        Array::from_vec_with_order::<RowMajor>(shape, data)
+    }
+}
+
+pub fn write_npy<A, W>(mut writer: W, array: &A) -> Result<()>
+where
+    A: NDArray,
+    <A::Storage as Storage>::Elem: NpyElement,
+    W: Write,
+{
+    let descr = <A::Storage as Storage>::Elem::DESCR;
+    let header = Header {
+        descr: format!("{}{}", if &descr[1..] == "1" { "|" } else { "<" }, descr),
+        fortran_order: util::type_eq::<A::Order, ColumnMajor>(),
+        shape: array.shape().as_ref().to_vec(),
+    };
+    writer.write_all(&header.to_bytes())?;
+
+    let mut buf = Vec::new();
+    if header.fortran_order {
+        for elem in array.transpose().iter() {
+            elem.write_bytes(&mut buf);
+        }
+    } else {
+        for elem in array.iter() {
+            elem.write_bytes(&mut buf);
+        }
+    }
+    writer.write_all(&buf)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use num_complex::Complex;
+
+    use super::{read_npy, write_npy, Header};
+    use crate::{
+        array, Array, ColumnMajor, DynDim, Error, NDArray, NDims, Result, RowMajor, ShapeError,
+    };
+
+    fn npy_bytes(major: u8, dict: &str, data: &[u8]) -> Vec<u8> {
+        let mut out = b"\x93NUMPY".to_vec();
+        out.extend_from_slice(&[major, 0]);
+        if major == 1 {
+            out.extend_from_slice(&(dict.len() as u16).to_le_bytes());
+        } else {
+            out.extend_from_slice(&(dict.len() as u32).to_le_bytes());
+        }
+        out.extend_from_slice(dict.as_bytes());
+        out.extend_from_slice(data);
+        out
+    }
+
+    #[test]
+    fn parse_header() -> Result<()> {
+        let header = Header::parse("{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }")?;
+
+        assert_eq!(
+            header,
+            Header {
+                descr: "<f8".into(),
+                fortran_order: false,
+                shape: vec![2, 3],
+            }
+        );
+        assert_eq!(
+            Header::parse("{\"shape\": (), \"descr\": \"|b1\", \"fortran_order\": True}")?.shape,
+            vec![]
+        );
+        assert_eq!(
+            Header::parse("{'descr': '<f8', 'fortran_order': False, 'shape': (4,)}")?.shape,
+            vec![4]
+        );
+        assert!(Header::parse("{'descr': '<f8', 'shape': (4,)}").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn align_header() {
+        let header = Header {
+            descr: "<f8".into(),
+            fortran_order: false,
+            shape: vec![2, 3],
+        };
+        let bytes = header.to_bytes();
+
+        assert_eq!(bytes.len() % 64, 0);
+        assert_eq!(bytes.last(), Some(&b'\n'));
+    }
+
+    #[test]
+    fn read_big_endian_v2() -> Result<()> {
+        let data = [1_i32, 2, 3, 4]
+            .iter()
+            .flat_map(|x| x.to_be_bytes())
+            .collect::<Vec<_>>();
+        let bytes = npy_bytes(
+            2,
+            "{'descr': '>i4', 'fortran_order': False, 'shape': (2, 2), }\n",
+            &data,
+        );
+        let a = read_npy::<i32, NDims<2>, RowMajor, _>(bytes.as_slice())?;
+
+        assert_eq!(a, array!([[1, 2], [3, 4]]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_fortran_order() -> Result<()> {
+        let data = [1_u16, 4, 2, 5, 3, 6]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect::<Vec<_>>();
+        let bytes = npy_bytes(
+            3,
+            "{'descr': '<u2', 'fortran_order': True, 'shape': (2, 3), }\n",
+            &data,
+        );
+        {
+            let a = read_npy::<u16, DynDim, RowMajor, _>(bytes.as_slice())?;
+
+            assert_eq!(a.shape(), &vec![2, 3]);
+            assert_eq!(
+                a.iter().cloned().collect::<Vec<_>>(),
+                vec![1, 2, 3, 4, 5, 6]
+            );
+        }
+        {
+            let a = read_npy::<u16, DynDim, ColumnMajor, _>(bytes.as_slice())?;
+
+            assert_eq!(a.strides(), &vec![1, 2]);
+            assert_eq!(
+                a.iter().cloned().collect::<Vec<_>>(),
+                vec![1, 2, 3, 4, 5, 6]
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_with_mismatched_dtype() {
+        let bytes = npy_bytes(
+            1,
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (1,), }\n",
+            &[0; 4],
+        );
+
+        assert!(matches!(
+            read_npy::<f64, DynDim, RowMajor, _>(bytes.as_slice()),
+            Err(Error::Dtype(_))
+        ));
+    }
+
+    #[test]
+    fn read_invalid_header() {
+        for header in [
+            "{'descr': '', 'fortran_order': False, 'shape': (1,), }\n",
+            "{'descr': '\u{e9}f8', 'fortran_order': False, 'shape': (1,), }\n",
+        ] {
+            assert!(matches!(
+                read_npy::<f64, DynDim, RowMajor, _>(npy_bytes(3, header, &[0; 8]).as_slice()),
+                Err(Error::Dtype(_))
+            ));
+        }
+
+        let bytes = npy_bytes(
+            1,
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (4294967296, 4294967296), }\n",
+            &[0; 8],
+        );
+
+        assert!(matches!(
+            read_npy::<f64, DynDim, RowMajor, _>(bytes.as_slice()),
+            Err(Error::Format(_))
+        ));
+    }
+
+    #[test]
+    fn read_truncated() {
+        let mut bytes = b"\x93NUMPY\x02\x00".to_vec();
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+
+        assert!(matches!(
+            read_npy::<f64, DynDim, RowMajor, _>(bytes.as_slice()),
+            Err(Error::Io(_))
+        ));
+
+        let bytes = npy_bytes(
+            1,
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (1099511627776,), }\n",
+            &[0; 8],
+        );
+
+        assert!(matches!(
+            read_npy::<f64, DynDim, RowMajor, _>(bytes.as_slice()),
+            Err(Error::Io(_))
+        ));
+    }
+
+    #[test]
+    fn read_with_mismatched_rank() {
+        let bytes = npy_bytes(
+            1,
+            "{'descr': '<f4', 'fortran_order': False, 'shape': (1,), }\n",
+            &[0; 4],
+        );
+
+        assert!(matches!(
+            read_npy::<f32, NDims<2>, RowMajor, _>(bytes.as_slice()),
+            Err(Error::Shape(ShapeError::IncompatibleDimension(_)))
+        ));
+    }
+
+    #[test]
+    fn write_and_read() -> Result<()> {
+        macro_rules! roundtrip {
+            ($array:expr, $type:ty) => {
+                let mut buf = Vec::new();
+                write_npy(&mut buf, &$array)?;
+                let actual = read_npy::<$type, _, _, _>(buf.as_slice())?;
+
+                assert_eq!(actual, $array);
+            };
+        }
+
+        roundtrip!(array!([[1.5_f64, 2.], [3., 4.]]), f64);
+        roundtrip!(array!([true, false, true]), bool);
+        roundtrip!(array!([1_u8, 2, 3]), u8);
+        roundtrip!(array!(7_i64), i64);
+        roundtrip!(
+            Array::from(vec![Complex::new(1_f32, 2.), Complex::new(3., 4.)]),
+            Complex<f32>
+        );
+        roundtrip!(
+            Array::<i16, NDims<2>, ColumnMajor>::from_vec_with_order::<RowMajor>(
+                [2, 3],
+                vec![1, 2, 3, 4, 5, 6]
+            )?,
+            i16
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_column_major() -> Result<()> {
+        let a = Array::<u8, NDims<2>, ColumnMajor>::from_vec_with_order::<RowMajor>(
+            [2, 3],
+            vec![1, 2, 3, 4, 5, 6],
+        )?;
+        let mut buf = Vec::new();
+        write_npy(&mut buf, &a)?;
+
+        assert!(String::from_utf8_lossy(&buf).contains("'fortran_order': True"));
+        assert_eq!(&buf[buf.len() - 6..], &[1, 4, 2, 5, 3, 6]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use std::{
+    collections::HashMap,
+    io::{Read, Seek, Write},
+};
+
+use zip::{result::ZipError, write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use super::npy::{read_npy, write_npy, NpyElement};
+use crate::{storage::Storage, Array, DynDim, Error, NDArray, Result, RowMajor};
+
+impl From<ZipError> for Error {
+    fn from(e: ZipError) -> Self {
+        match e {
+            ZipError::Io(e) => e.into(),
+            e => Self::Format(e.to_string()),
+        }
+    }
+}
+
+pub struct NpzReader<R> {
+    archive: ZipArchive<R>,
+    names: Vec<String>,
+}
+
+impl<R> NpzReader<R>
+where
+    R: Read + Seek,
+{
+    pub fn new(reader: R) -> Result<Self> {
+        let mut archive = ZipArchive::new(reader)?;
+        let names = (0..archive.len())
+            .map(|i| {
+                let file = archive.by_index(i)?;
+                let name = file.name();
+                Ok(name.strip_suffix(".npy").unwrap_or(name).to_string())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { archive, names })
+    }
+
+    pub fn by_name<T>(&mut self, name: &str) -> Result<Array<T, DynDim>>
+    where
+        T: NpyElement,
+    {
+        let i = self
+            .names
+            .iter()
+            .position(|n| n == name)
+            .ok_or_else(|| Error::Value(format!("no array named '{}'", name)))?;
+        read_npy::<T, DynDim, RowMajor, _>(self.archive.by_index(i)?)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+}
+
+pub fn read_npz<T, R>(reader: R) -> Result<HashMap<String, Array<T, DynDim>>>
+where
+    R: Read + Seek,
+    T: NpyElement,
+{
+    let mut npz = NpzReader::new(reader)?;
+    let mut arrays = HashMap::with_capacity(npz.names.len());
+    for i in 0..npz.names.len() {
+        let array = read_npy::<T, DynDim, RowMajor, _>(npz.archive.by_index(i)?)?;
+        arrays.insert(npz.names[i].clone(), array);
+    }
+    Ok(arrays)
+}
+
+pub struct NpzWriter<W>
+where
+    W: Write + Seek,
+{
+    zip: ZipWriter<W>,
+    options: FileOptions,
+}
+
+impl<W> NpzWriter<W>
+where
+    W: Write + Seek,
+{
+    pub fn new(writer: W) -> Self {
+        Self::with_method(writer, CompressionMethod::Stored)
+    }
+
+    pub fn new_compressed(writer: W) -> Self {
+        Self::with_method(writer, CompressionMethod::Deflated)
+    }
+
+    fn with_method(writer: W, method: CompressionMethod) -> Self {
+        Self {
+            zip: ZipWriter::new(writer),
+            options: FileOptions::default()
+                .compression_method(method)
+                .large_file(true),
+        }
+    }
+
+    pub fn add_array<A>(&mut self, name: &str, array: &A) -> Result<()>
+    where
+        A: NDArray,
+        <A::Storage as Storage>::Elem: NpyElement,
+    {
+        self.zip.start_file(format!("{}.npy", name), self.options)?;
+        write_npy(&mut self.zip, array)
+    }
+
+    pub fn finish(mut self) -> Result<W> {
+        Ok(self.zip.finish()?)
+    }
+}
+
+pub fn write_npz<'a, A, I, N, W>(writer: W, arrays: I) -> Result<()>
+where
+    A: NDArray + 'a,
+    <A::Storage as Storage>::Elem: NpyElement,
+    I: IntoIterator<Item = (N, &'a A)>,
+    N: AsRef<str>,
+    W: Write + Seek,
+{
+    write_all(NpzWriter::new(writer), arrays)
+}
+
+pub fn write_npz_compressed<'a, A, I, N, W>(writer: W, arrays: I) -> Result<()>
+where
+    A: NDArray + 'a,
+    <A::Storage as Storage>::Elem: NpyElement,
+    I: IntoIterator<Item = (N, &'a A)>,
+    N: AsRef<str>,
+    W: Write + Seek,
+{
+    write_all(NpzWriter::new_compressed(writer), arrays)
+}
+
+fn write_all<'a, A, I, N, W>(mut npz: NpzWriter<W>, arrays: I) -> Result<()>
+where
+    A: NDArray + 'a,
+    <A::Storage as Storage>::Elem: NpyElement,
+    I: IntoIterator<Item = (N, &'a A)>,
+    N: AsRef<str>,
+    W: Write + Seek,
+{
+    for (name, array) in arrays {
+        npz.add_array(name.as_ref(), array)?;
+    }
+    npz.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::{read_npz, write_npz, write_npz_compressed, NpzReader, NpzWriter};
+    use crate::{array, Error, NDArray, Result};
+
+    #[test]
+    fn write_and_read() -> Result<()> {
+        let weight = array!([[1_f32, 2., 3.], [4., 5., 6.]]);
+        let bias = array!([[7_f32, 8., 9.]]);
+
+        for compressed in [false, true] {
+            let mut buf = Cursor::new(Vec::new());
+            let arrays = vec![("weight", &weight), ("bias", &bias)];
+            if compressed {
+                write_npz_compressed(&mut buf, arrays)?;
+            } else {
+                write_npz(&mut buf, arrays)?;
+            }
+            buf.set_position(0);
+            let arrays = read_npz::<f32, _>(buf)?;
+
+            assert_eq!(arrays.len(), 2);
+            assert_eq!(arrays["weight"].shape(), &vec![2, 3]);
+            assert_eq!(
+                arrays["weight"].iter().cloned().collect::<Vec<_>>(),
+                vec![1., 2., 3., 4., 5., 6.]
+            );
+            assert_eq!(arrays["bias"].shape(), &vec![1, 3]);
+            assert_eq!(
+                arrays["bias"].iter().cloned().collect::<Vec<_>>(),
+                vec![7., 8., 9.]
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_and_read_mixed_types() -> Result<()> {
+        let x = array!([1.5_f64, 2.5]);
+        let labels = array!([[1_u8, 2], [3, 4]]);
+        let mut npz = NpzWriter::new_compressed(Cursor::new(Vec::new()));
+        npz.add_array("x", &x)?;
+        npz.add_array("labels", &labels)?;
+        let mut buf = npz.finish()?;
+        buf.set_position(0);
+
+        let mut npz = NpzReader::new(buf)?;
+
+        assert_eq!(npz.names().collect::<Vec<_>>(), vec!["x", "labels"]);
+        assert_eq!(
+            npz.by_name::<f64>("x")?.iter().cloned().collect::<Vec<_>>(),
+            vec![1.5, 2.5]
+        );
+        assert_eq!(npz.by_name::<u8>("labels")?.shape(), &vec![2, 2]);
+        assert!(matches!(npz.by_name::<u8>("x"), Err(Error::Dtype(_))));
+        assert!(matches!(npz.by_name::<u8>("y"), Err(Error::Value(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_invalid_archive() {
+        assert!(read_npz::<f64, _>(Cursor::new(b"not a zip archive".to_vec())).is_err());
+    }
+}
@@ -0,0 +1,421 @@
+use std::{borrow::Cow, collections::HashMap, io::Write, mem, slice, str};
+
+use super::{
+    bytes::{to_bytes, vec_from_bytes, Endian, Pod},
+    json::{escape, JsonValue},
+};
+use crate::{
+    storage::{Storage, StorageBase},
+    ArrayBase, DynDim, Error, NDArray, Result, RowMajor, ShapeError,
+};
+
+const HEADER_ALIGNMENT: usize = 8;
+const METADATA_KEY: &str = "__metadata__";
+
+pub trait SafeTensorsElement: Pod {
+    const DTYPE: &'static str;
+}
+
+macro_rules! impl_safetensors_element {
+    ($type:ty, $dtype:expr) => {
+        impl SafeTensorsElement for $type {
+            const DTYPE: &'static str = $dtype;
+        }
+    };
+}
+
+impl_safetensors_element!(u8, "U8");
+impl_safetensors_element!(u16, "U16");
+impl_safetensors_element!(u32, "U32");
+impl_safetensors_element!(u64, "U64");
+impl_safetensors_element!(i8, "I8");
+impl_safetensors_element!(i16, "I16");
+impl_safetensors_element!(i32, "I32");
+impl_safetensors_element!(i64, "I64");
+impl_safetensors_element!(f32, "F32");
+impl_safetensors_element!(f64, "F64");
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct TensorInfo {
+    dtype: String,
+    shape: Vec<usize>,
+    begin: usize,
+    end: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct SafeTensors<'a> {
+    data: &'a [u8],
+    tensors: Vec<(String, TensorInfo)>,
+    metadata: HashMap<String, String>,
+}
+
+impl<'a> SafeTensors<'a> {
+    pub fn deserialize(buffer: &'a [u8]) -> Result<Self> {
+        let header_end = buffer
+            .get(..8)
+            .and_then(|bytes| usize::try_from(u64::from_le_bytes(bytes.try_into().unwrap())).ok())
+            .and_then(|header_len| header_len.checked_add(8))
+            .ok_or_else(|| Error::Format("invalid safetensors header length".into()))?;
+        let header = buffer
+            .get(8..header_end)
+            .and_then(|header| str::from_utf8(header).ok())
+            .ok_or_else(|| Error::Format("invalid safetensors header".into()))?;
+        let header = JsonValue::parse(header)?;
+        let data = &buffer[header_end..];
+
+        let mut tensors = Vec::new();
+        let mut metadata = HashMap::new();
+        for (name, value) in header
+            .as_object()
+            .ok_or_else(|| Error::Format("safetensors header must be an object".into()))?
+        {
+            if name == METADATA_KEY {
+                for (key, value) in value.as_object().unwrap_or_default() {
+                    if let Some(value) = value.as_str() {
+                        metadata.insert(key.clone(), value.to_string());
+                    }
+                }
+                continue;
+            }
+
+            let info = parse_tensor_info(value)
+                .ok_or_else(|| Error::Format(format!("invalid entry for tensor '{}'", name)))?;
+            if info.begin > info.end || info.end > data.len() {
+                return Err(Error::Format(format!(
+                    "data offsets of tensor '{}' are out of bounds",
+                    name
+                )));
+            }
+            tensors.push((name.clone(), info));
+        }
+
+        Ok(Self {
+            data,
+            tensors,
+            metadata,
+        })
+    }
+
+    pub fn dtype(&self, name: &str) -> Option<&str> {
+        self.info(name).map(|info| info.dtype.as_str())
+    }
+
+    pub fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.tensors.iter().map(|(name, _)| name.as_str())
+    }
+
+    pub fn shape(&self, name: &str) -> Option<&[usize]> {
+        self.info(name).map(|info| info.shape.as_slice())
+    }
+
+    pub fn tensor<T>(&self, name: &str) -> Result<ArrayBase<StorageBase<Cow<'a, [T]>>, DynDim>>
+    where
+        T: SafeTensorsElement,
+    {
+        let info = self
+            .info(name)
+            .ok_or_else(|| Error::Value(format!("no tensor named '{}'", name)))?;
+        if info.dtype != T::DTYPE {
+            return Err(Error::Dtype(format!(
+                "cannot read tensor '{}' of dtype {} as {}",
+                name,
+                info.dtype,
+                T::DTYPE
+            )));
+        }
+
+        let bytes = &self.data[info.begin..info.end];
+        let len = info
+            .shape
+            .iter()
+            .try_fold(1_usize, |len, &dim| len.checked_mul(dim))
+            .ok_or_else(|| {
+                Error::Format(format!(
+                    "shape {:?} of tensor '{}' is too large",
+                    info.shape, name
+                ))
+            })?;
+        if len.checked_mul(mem::size_of::<T>()) != Some(bytes.len()) {
+            return Err(ShapeError::IncompatibleShape(format!(
+                "tensor '{}' of shape {:?} has {} bytes",
+                name,
+                info.shape,
+                bytes.len()
+            ))
+            .into());
+        }
+
+        let data = if Endian::NATIVE == Endian::Little
+            && (bytes.as_ptr() as usize).is_multiple_of(mem::align_of::<T>())
+        {
+            Cow::Borrowed(unsafe { slice::from_raw_parts(bytes.as_ptr() as *const T, len) })
+        } else {
+            Cow::Owned(vec_from_bytes(bytes, Endian::Little))
+        };
+        ArrayBase::from_shape_storage(info.shape.clone(), StorageBase::from(data))
+    }
+
+    fn info(&self, name: &str) -> Option<&TensorInfo> {
+        self.tensors
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, info)| info)
+    }
+}
+
+fn parse_tensor_info(value: &JsonValue) -> Option<TensorInfo> {
+    let dtype = value.get("dtype")?.as_str()?.to_string();
+    let shape = value
+        .get("shape")?
+        .as_array()?
+        .iter()
+        .map(JsonValue::as_usize)
+        .collect::<Option<Vec<_>>>()?;
+    let offsets = value.get("data_offsets")?.as_array()?;
+    match offsets {
+        [begin, end] => Some(TensorInfo {
+            dtype,
+            shape,
+            begin: begin.as_usize()?,
+            end: end.as_usize()?,
+        }),
+        _ => None,
+    }
+}
+
+struct Entry {
+    name: String,
+    dtype: &'static str,
+    shape: Vec<usize>,
+    data: Vec<u8>,
+}
+
+pub struct SafeTensorsWriter<W> {
+    writer: W,
+    entries: Vec<Entry>,
+}
+
+impl<W> SafeTensorsWriter<W>
+where
+    W: Write,
+{
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn add_tensor<A>(&mut self, name: &str, array: &A)
+    where
+        A: NDArray,
+        <A::Storage as Storage>::Elem: SafeTensorsElement,
+    {
+        self.entries.push(Entry {
+            name: name.to_string(),
+            dtype: <A::Storage as Storage>::Elem::DTYPE,
+            shape: array.shape().as_ref().to_vec(),
+            data: to_bytes::<RowMajor, _>(array, Endian::Little),
+        });
+    }
+
+    pub fn finish(mut self, metadata: Option<&HashMap<String, String>>) -> Result<W> {
+        let mut entries = Vec::new();
+        if let Some(metadata) = metadata {
+            let mut metadata = metadata.iter().collect::<Vec<_>>();
+            metadata.sort();
+            let members = metadata
+                .iter()
+                .map(|(key, value)| format!("{}:{}", escape(key), escape(value)))
+                .collect::<Vec<_>>();
+            entries.push(format!(
+                "{}:{{{}}}",
+                escape(METADATA_KEY),
+                members.join(",")
+            ));
+        }
+
+        let mut offset = 0;
+        for entry in &self.entries {
+            let shape = entry
+                .shape
+                .iter()
+                .map(|dim| dim.to_string())
+                .collect::<Vec<_>>();
+            entries.push(format!(
+                "{}:{{\"dtype\":\"{}\",\"shape\":[{}],\"data_offsets\":[{},{}]}}",
+                escape(&entry.name),
+                entry.dtype,
+                shape.join(","),
+                offset,
+                offset + entry.data.len()
+            ));
+            offset += entry.data.len();
+        }
+
+        let mut header = format!("{{{}}}", entries.join(","));
+        let padding = (HEADER_ALIGNMENT - header.len() % HEADER_ALIGNMENT) % HEADER_ALIGNMENT;
+        header.push_str(&" ".repeat(padding));
+
+        self.writer
+            .write_all(&(header.len() as u64).to_le_bytes())?;
+        self.writer.write_all(header.as_bytes())?;
+        for entry in &self.entries {
+            self.writer.write_all(&entry.data)?;
+        }
+        Ok(self.writer)
+    }
+}
+
+pub fn write_safetensors<'a, A, I, N, W>(
+    writer: W,
+    tensors: I,
+    metadata: Option<&HashMap<String, String>>,
+) -> Result<()>
+where
+    A: NDArray + 'a,
+    <A::Storage as Storage>::Elem: SafeTensorsElement,
+    I: IntoIterator<Item = (N, &'a A)>,
+    N: AsRef<str>,
+    W: Write,
+{
+    let mut safetensors = SafeTensorsWriter::new(writer);
+    for (name, array) in tensors {
+        safetensors.add_tensor(name.as_ref(), array);
+    }
+    safetensors.finish(metadata)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{write_safetensors, SafeTensors, SafeTensorsWriter};
+    use crate::{array, Array, Error, NDArray, NDArrayOwned, Result};
+
+    fn serialize() -> Result<Vec<u8>> {
+        let weight = array!([[1_f32, 2., 3.], [4., 5., 6.]]).into_shape(vec![2_isize, 3])?;
+        let bias = Array::from(vec![7_f32, 8.]).into_shape(vec![2_isize])?;
+        let metadata = HashMap::from([("format".to_string(), "pt".to_string())]);
+        let mut buf = Vec::new();
+        write_safetensors(
+            &mut buf,
+            vec![("weight", &weight), ("bias", &bias)],
+            Some(&metadata),
+        )?;
+        Ok(buf)
+    }
+
+    #[test]
+    fn write_and_read() -> Result<()> {
+        let buf = serialize()?;
+        let header_len = u64::from_le_bytes(buf[..8].try_into().unwrap()) as usize;
+
+        assert_eq!(header_len % 8, 0);
+
+        let tensors = SafeTensors::deserialize(&buf)?;
+
+        assert_eq!(tensors.names().collect::<Vec<_>>(), vec!["weight", "bias"]);
+        assert_eq!(tensors.metadata()["format"], "pt");
+        assert_eq!(tensors.dtype("weight"), Some("F32"));
+        assert_eq!(tensors.shape("bias"), Some(&[2][..]));
+
+        let weight = tensors.tensor::<f32>("weight")?;
+
+        assert_eq!(weight.shape(), &vec![2, 3]);
+        assert_eq!(
+            weight.iter().cloned().collect::<Vec<_>>(),
+            vec![1., 2., 3., 4., 5., 6.]
+        );
+        if cfg!(target_endian = "little") && (buf.as_ptr() as usize).is_multiple_of(4) {
+            assert!(buf.as_ptr_range().contains(&(weight.as_ptr() as *const u8)));
+        }
+        assert!(matches!(
+            tensors.tensor::<f64>("weight"),
+            Err(Error::Dtype(_))
+        ));
+        assert!(tensors.tensor::<f32>("missing").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_and_read_mixed_types() -> Result<()> {
+        let mut safetensors = SafeTensorsWriter::new(Vec::new());
+        safetensors.add_tensor("x", &array!([1.5_f64, 2.5]));
+        safetensors.add_tensor("ids", &array!([[1_i64, 2], [3, 4]]));
+        let buf = safetensors.finish(None)?;
+        let tensors = SafeTensors::deserialize(&buf)?;
+
+        assert_eq!(tensors.dtype("x"), Some("F64"));
+        assert_eq!(tensors.dtype("ids"), Some("I64"));
+        assert_eq!(
+            tensors
+                .tensor::<f64>("x")?
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![1.5, 2.5]
+        );
+        assert_eq!(
+            tensors
+                .tensor::<i64>("ids")?
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_unaligned_buffer() -> Result<()> {
+        let buf = serialize()?;
+        let mut shifted = vec![0_u8; buf.len() + 1];
+        shifted[1..].copy_from_slice(&buf);
+        let tensors = SafeTensors::deserialize(&shifted[1..])?;
+        let bias = tensors.tensor::<f32>("bias")?;
+
+        assert_eq!(bias.iter().cloned().collect::<Vec<_>>(), vec![7., 8.]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_invalid_buffer() {
+        assert!(SafeTensors::deserialize(&[0; 4]).is_err());
+
+        let header = br#"{"a":{"dtype":"U8","shape":[4],"data_offsets":[0,4]}}"#;
+        let mut buf = (header.len() as u64).to_le_bytes().to_vec();
+        buf.extend_from_slice(header);
+        buf.extend_from_slice(&[1, 2]);
+
+        assert!(matches!(
+            SafeTensors::deserialize(&buf),
+            Err(Error::Format(_))
+        ));
+
+        let mut buf = u64::MAX.to_le_bytes().to_vec();
+        buf.extend_from_slice(b"{}");
+
+        assert!(matches!(
+            SafeTensors::deserialize(&buf),
+            Err(Error::Format(_))
+        ));
+
+        let header =
+            br#"{"a":{"dtype":"U8","shape":[4294967296,4294967296],"data_offsets":[0,0]}}"#;
+        let mut buf = (header.len() as u64).to_le_bytes().to_vec();
+        buf.extend_from_slice(header);
+        let tensors = SafeTensors::deserialize(&buf).unwrap();
+
+        assert!(matches!(tensors.tensor::<u8>("a"), Err(Error::Format(_))));
+    }
+}
@@ -11,25 +11,39 @@
     unchecked_math
 )]
 #![cfg_attr(not(feature = "std"), no_std)]
+// Error messages are converted into `String`s when an allocator is available
+#![cfg_attr(not(feature = "alloc"), allow(clippy::useless_conversion))]
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 #[macro_use]
 extern crate alloc;
 
+#[macro_use]
+mod error;
+pub use error::{Error, Message, Result, ShapeError};
+
 mod array;
-pub use array::{Array, ArrayBase, Iter, IterMut};
+#[cfg(feature = "std")]
+pub use array::set_print_options;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use array::ElementMath;
+#[cfg(feature = "alloc")]
+pub use array::{
+    indices, meshgrid, meshgrid_sparse, print_options, AlignedArray, Array, DisplayWith,
+    FormatElement, Indexing, LatexStyle, PrintOptions, Promote, RangeElement, Sign, TableOptions,
+};
+pub use array::{ArrayBase, Iter, IterMut};
 
 mod array_index;
 pub use array_index::{ArrayIndex, NewAxis};
 
 mod dimension;
 pub use dimension::{
-    DimDiff, Dimensionality, DimensionalityAdd, DimensionalityAfterDot, DimensionalityDiff,
-    DimensionalityMax, DynDim, DynDimDiff, NDims,
+    DimDiff, Dimensionality, DimensionalityAdd, DimensionalityAfterDot, DimensionalityBase,
+    DimensionalityDiff, DimensionalityMax, NDims,
 };
-
-mod error;
-pub use error::{Error, Result, ShapeError};
+#[cfg(feature = "alloc")]
+pub use dimension::{DynDim, DynDimDiff};
 
 mod linalg;
 pub use linalg::Dot;
@@ -50,8 +64,14 @@ pub use shape::{Shape, SignedShape};
 
 mod util;
 
+#[cfg(feature = "alloc")]
+pub mod io;
+#[cfg(feature = "alloc")]
+pub mod random;
 pub mod storage;
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
 use num_traits::{One, Zero};
 
 use storage::{Storage, StorageMut, StorageOwned};
@@ -190,6 +210,7 @@ where
     fn allocate_uninitialized<Sh>(shape: &Sh) -> Self
     where
         Sh: Shape<Dimensionality = Self::Dimensionality>;
+    #[cfg(feature = "alloc")]
     fn concatenate<T>(arrays: &[T], axis: isize) -> Result<Self>
     where
         Self: Sized,
@@ -197,6 +218,35 @@ where
         <<T as NDArray>::Dimensionality as Dimensionality>::Shape:
             Shape<Dimensionality = Self::Dimensionality>,
         <T as NDArray>::Storage: Storage<Elem = <Self::Storage as Storage>::Elem>;
+    #[cfg(feature = "alloc")]
+    fn from_shape_fn<Sh, F>(shape: &Sh, f: F) -> Self
+    where
+        F: FnMut(
+            &<Self::Dimensionality as Dimensionality>::Shape,
+        ) -> <Self::Storage as Storage>::Elem,
+        Sh: Shape<Dimensionality = Self::Dimensionality>;
+    #[cfg(feature = "alloc")]
+    fn from_shape_vec<Sh>(shape: Sh, data: Vec<<Self::Storage as Storage>::Elem>) -> Result<Self>
+    where
+        Self: Sized,
+        Sh: Shape<Dimensionality = Self::Dimensionality>;
+    #[cfg(feature = "alloc")]
+    fn from_shape_vec_with_order<Sh, O2>(
+        shape: Sh,
+        data: Vec<<Self::Storage as Storage>::Elem>,
+    ) -> Result<Self>
+    where
+        Self: Sized,
+        Sh: Shape<Dimensionality = Self::Dimensionality>,
+        O2: Order;
+    fn full<Sh>(shape: &Sh, value: <Self::Storage as Storage>::Elem) -> Self
+    where
+        Sh: Shape<Dimensionality = Self::Dimensionality>;
+    fn full_like<A>(array: &A, value: <Self::Storage as Storage>::Elem) -> Self
+    where
+        A: NDArray,
+        <<A as NDArray>::Dimensionality as Dimensionality>::Shape:
+            Shape<Dimensionality = Self::Dimensionality>;
     fn into_shape<Sh>(self, shape: Sh) -> Result<Self::WithD<<Sh as SignedShape>::Dimensionality>>
     where
         Sh: SignedShape;
@@ -211,6 +261,13 @@ where
     where
         <Self::Storage as Storage>::Elem: One,
         Sh: Shape<Dimensionality = Self::Dimensionality>;
+    fn ones_like<A>(array: &A) -> Self
+    where
+        <Self::Storage as Storage>::Elem: One,
+        A: NDArray,
+        <<A as NDArray>::Dimensionality as Dimensionality>::Shape:
+            Shape<Dimensionality = Self::Dimensionality>;
+    #[cfg(feature = "alloc")]
     fn stack<T>(arrays: &[T], axis: isize) -> Result<Self>
     where
         Self: Sized,
@@ -220,8 +277,34 @@ where
             as Dimensionality>::SignedShape as SignedShape>::Dimensionality
             as Dimensionality>::Shape: Shape<Dimensionality = Self::Dimensionality>,
         <T as NDArray>::Storage: Storage<Elem = <Self::Storage as Storage>::Elem>;
+    /// Like [`NDArrayOwned::full`], but returns an error instead of panicking
+    /// when the storage cannot hold an array of `shape`.
+    fn try_full<Sh>(shape: &Sh, value: <Self::Storage as Storage>::Elem) -> Result<Self>
+    where
+        Self: Sized,
+        Sh: Shape<Dimensionality = Self::Dimensionality>;
+    /// Like [`NDArrayOwned::ones`], but returns an error instead of panicking
+    /// when the storage cannot hold an array of `shape`.
+    fn try_ones<Sh>(shape: &Sh) -> Result<Self>
+    where
+        Self: Sized,
+        <Self::Storage as Storage>::Elem: One,
+        Sh: Shape<Dimensionality = Self::Dimensionality>;
+    /// Like [`NDArrayOwned::zeros`], but returns an error instead of panicking
+    /// when the storage cannot hold an array of `shape`.
+    fn try_zeros<Sh>(shape: &Sh) -> Result<Self>
+    where
+        Self: Sized,
+        <Self::Storage as Storage>::Elem: Zero,
+        Sh: Shape<Dimensionality = Self::Dimensionality>;
     fn zeros<Sh>(shape: &Sh) -> Self
     where
         <Self::Storage as Storage>::Elem: Zero,
         Sh: Shape<Dimensionality = Self::Dimensionality>;
+    fn zeros_like<A>(array: &A) -> Self
+    where
+        <Self::Storage as Storage>::Elem: Zero,
+        A: NDArray,
+        <<A as NDArray>::Dimensionality as Dimensionality>::Shape:
+            Shape<Dimensionality = Self::Dimensionality>;
 }
@@ -1,3 +1,12 @@
+/// Dot product of arrays.
+///
+/// Operands may have different orders. The result is laid out in the order of
+/// the left operand; `ArrayBase::dot_with_order` chooses it explicitly.
+///
+/// # Panics
+///
+/// Panics if either operand is 0-dimensional or the contracted axes differ in
+/// length.
 pub trait Dot<Rhs> {
     type Output;
     fn dot(&self, rhs: Rhs) -> Self::Output;
@@ -0,0 +1,366 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::f64::consts::{LN_2, SQRT_2};
+
+use num_traits::{Num, NumCast};
+
+use crate::{
+    array::routine::normalize_axis, storage::StorageMut, Array, ArrayIndex, DimensionalityAdd,
+    DynDimDiff, Error, NDArray, NDArrayMut, NDArrayOwned, NDims, Result, Shape, SliceInfo,
+};
+
+pub trait SampleFloat: Copy + NumCast {
+    fn sample_unit(rng: &mut Rng) -> Self;
+}
+
+impl SampleFloat for f32 {
+    fn sample_unit(rng: &mut Rng) -> Self {
+        (rng.next_u64() >> 40) as f32 * (1. / (1_u64 << 24) as f32)
+    }
+}
+
+impl SampleFloat for f64 {
+    fn sample_unit(rng: &mut Rng) -> Self {
+        rng.next_f64()
+    }
+}
+
+pub trait SampleInteger: Copy + PartialOrd {
+    fn sample_range(rng: &mut Rng, low: Self, high: Self) -> Self;
+}
+
+macro_rules! impl_sample_integer {
+    ($type:ty) => {
+        impl SampleInteger for $type {
+            fn sample_range(rng: &mut Rng, low: Self, high: Self) -> Self {
+                let span = (high as i128 - low as i128) as u64;
+                let threshold = span.wrapping_neg() % span;
+                loop {
+                    let r = rng.next_u64();
+                    if r >= threshold {
+                        return (low as i128 + (r % span) as i128) as $type;
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_sample_integer!(u8);
+impl_sample_integer!(u16);
+impl_sample_integer!(u32);
+impl_sample_integer!(u64);
+impl_sample_integer!(usize);
+impl_sample_integer!(i8);
+impl_sample_integer!(i16);
+impl_sample_integer!(i32);
+impl_sample_integer!(i64);
+impl_sample_integer!(isize);
+
+// xoshiro256** seeded through splitmix64
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        let mut z = seed;
+        let mut state = [0; 4];
+        for s in state.iter_mut() {
+            z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut x = z;
+            x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            *s = x ^ (x >> 31);
+        }
+        Self { state }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1. / (1_u64 << 53) as f64)
+    }
+
+    pub fn bernoulli<Sh>(&mut self, shape: &Sh, p: f64) -> Result<Array<bool, Sh::Dimensionality>>
+    where
+        Sh: Shape,
+    {
+        if !(0. ..=1.).contains(&p) {
+            return Err(Error::Value(format!("probability {} is not in [0, 1]", p)));
+        }
+        Ok(Array::from_shape_fn(shape, |_| self.next_f64() < p))
+    }
+
+    pub fn integers<T, Sh>(
+        &mut self,
+        shape: &Sh,
+        low: T,
+        high: T,
+    ) -> Result<Array<T, Sh::Dimensionality>>
+    where
+        Sh: Shape,
+        T: SampleInteger,
+    {
+        if low >= high {
+            return Err(Error::Value("low must be less than high".into()));
+        }
+        Ok(Array::from_shape_fn(shape, |_| {
+            T::sample_range(self, low, high)
+        }))
+    }
+
+    pub fn normal<T, Sh>(
+        &mut self,
+        shape: &Sh,
+        mean: f64,
+        std_dev: f64,
+    ) -> Result<Array<T, Sh::Dimensionality>>
+    where
+        Sh: Shape,
+        T: SampleFloat,
+    {
+        if std_dev.is_nan() || std_dev < 0. {
+            return Err(Error::Value(
+                "standard deviation must be non-negative".into(),
+            ));
+        }
+        Ok(Array::from_shape_fn(shape, |_| {
+            T::from(mean + std_dev * self.sample_standard_normal()).unwrap()
+        }))
+    }
+
+    pub fn permutation(&mut self, n: usize) -> Array<usize, NDims<1>> {
+        let mut data = (0..n).collect::<Vec<_>>();
+        for i in (1..n).rev() {
+            data.swap(i, usize::sample_range(self, 0, i + 1));
+        }
+        Array::from(data)
+    }
+
+    pub fn shuffle_axis<A>(&mut self, array: &mut A, axis: isize) -> Result<()>
+    where
+        A: NDArrayMut,
+        A::Dimensionality: DimensionalityAdd<DynDimDiff>,
+        A::Storage: StorageMut,
+    {
+        let n_dims = array.ndims();
+        let axis = normalize_axis(axis, n_dims)?;
+        let info = |i: usize| {
+            SliceInfo::from(
+                (0..n_dims)
+                    .map(|j| {
+                        if j == axis {
+                            ArrayIndex::from(i as isize)
+                        } else {
+                            ArrayIndex::from(..)
+                        }
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        for i in (1..array.shape()[axis]).rev() {
+            let j = usize::sample_range(self, 0, i + 1);
+            if i == j {
+                continue;
+            }
+            let sub_i = array.slice(info(i)).iter().cloned().collect::<Vec<_>>();
+            let sub_j = array.slice(info(j)).iter().cloned().collect::<Vec<_>>();
+            for (dst, src) in array.slice_mut(info(i)).iter_mut().zip(sub_j) {
+                *dst = src;
+            }
+            for (dst, src) in array.slice_mut(info(j)).iter_mut().zip(sub_i) {
+                *dst = src;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn standard_normal<T, Sh>(&mut self, shape: &Sh) -> Array<T, Sh::Dimensionality>
+    where
+        Sh: Shape,
+        T: SampleFloat,
+    {
+        Array::from_shape_fn(shape, |_| T::from(self.sample_standard_normal()).unwrap())
+    }
+
+    pub fn uniform<T, Sh>(&mut self, shape: &Sh, low: T, high: T) -> Array<T, Sh::Dimensionality>
+    where
+        Sh: Shape,
+        T: Num + SampleFloat,
+    {
+        Array::from_shape_fn(shape, |_| low + (high - low) * T::sample_unit(self))
+    }
+
+    // Marsaglia's polar method; `ln` and `sqrt` are computed in-crate so that
+    // the samples are identical on every platform
+    fn sample_standard_normal(&mut self) -> f64 {
+        loop {
+            let u = 2. * self.next_f64() - 1.;
+            let v = 2. * self.next_f64() - 1.;
+            let s = u * u + v * v;
+            if s > 0. && s < 1. {
+                return u * sqrt(-2. * ln(s) / s);
+            }
+        }
+    }
+}
+
+fn ln(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let mut exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mut m = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+    if m > SQRT_2 {
+        m /= 2.;
+        exponent += 1;
+    }
+
+    // ln(m) = 2 atanh((m - 1) / (m + 1))
+    let s = (m - 1.) / (m + 1.);
+    let s2 = s * s;
+    let mut term = s;
+    let mut sum = 0.;
+    for k in (1..40).step_by(2) {
+        sum += term / k as f64;
+        term *= s2;
+    }
+    exponent as f64 * LN_2 + 2. * sum
+}
+
+fn sqrt(x: f64) -> f64 {
+    if x == 0. {
+        return 0.;
+    }
+    let mut y = f64::from_bits((x.to_bits() >> 1) + 0x1ff8_0000_0000_0000);
+    for _ in 0..6 {
+        y = 0.5 * (y + x / y);
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use super::Rng;
+    #[cfg(feature = "std")]
+    use super::{ln, sqrt};
+    use crate::{Array, Error, NDArray, NDArrayOwned, NDims, Result};
+
+    #[test]
+    fn reproducible_sequence() {
+        let mut rng = Rng::new(42);
+        let first = (0..3).map(|_| rng.next_u64()).collect::<Vec<_>>();
+
+        assert_eq!(
+            first,
+            vec![
+                1546998764402558742,
+                6990951692964543102,
+                12544586762248559009
+            ]
+        );
+        assert_eq!(Rng::new(42).next_u64(), first[0]);
+        assert_ne!(Rng::new(43).next_u64(), first[0]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn float_math() {
+        for &x in &[1e-300, 0.1, 0.5, 1., 2., 10., 1e300] {
+            assert!((ln(x) - x.ln()).abs() <= 1e-15 * x.ln().abs().max(1.));
+            assert!((sqrt(x) - x.sqrt()).abs() <= 1e-15 * x.sqrt());
+        }
+    }
+
+    #[test]
+    fn uniform_and_normal() -> Result<()> {
+        let mut rng = Rng::new(0);
+        let u = rng.uniform(&[1000], -1_f64, 3.);
+
+        assert!(u.iter().all(|&x| (-1. ..3.).contains(&x)));
+        assert!((u.iter().sum::<f64>() / 1000. - 1.).abs() < 0.1);
+
+        let n = rng.standard_normal::<f64, _>(&[100, 100]);
+        let mean = n.iter().sum::<f64>() / 10000.;
+        let var = n.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 10000.;
+
+        assert_eq!(n.shape(), &[100, 100]);
+        assert!(mean.abs() < 0.05);
+        assert!((var - 1.).abs() < 0.05);
+
+        let m = rng.normal::<f32, _>(&vec![1000], 5., 0.)?;
+
+        assert!(m.iter().all(|&x| x == 5.));
+        assert!(matches!(
+            rng.normal::<f32, _>(&[1], 0., -1.),
+            Err(Error::Value(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn integers_and_bernoulli() -> Result<()> {
+        let mut rng = Rng::new(1);
+        let a = rng.integers(&[1000], -2_i8, 3)?;
+
+        for v in -2..3 {
+            assert!(a.iter().any(|&x| x == v));
+        }
+        assert!(a.iter().all(|&x| (-2..3).contains(&x)));
+        assert!(rng.integers(&[1], 0_u64, u64::MAX).is_ok());
+        assert!(matches!(rng.integers(&[1], 3, 3), Err(Error::Value(_))));
+
+        let b = rng.bernoulli(&[1000], 0.25)?;
+        let count = b.iter().filter(|&&x| x).count();
+
+        assert!((200..300).contains(&count));
+        assert!(rng.bernoulli(&[1], 1.5).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn shuffle_and_permutation() -> Result<()> {
+        let mut rng = Rng::new(7);
+        let p = rng.permutation(10);
+        let mut sorted = p.iter().cloned().collect::<Vec<_>>();
+        sorted.sort_unstable();
+
+        assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+
+        let mut a = (0..12).collect::<Array<_, NDims<1>>>().into_shape([4, 3])?;
+        rng.shuffle_axis(&mut a, 0)?;
+        let mut rows = a
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>()
+            .chunks(3)
+            .map(|row| row.to_vec())
+            .collect::<Vec<_>>();
+
+        assert!(rows
+            .iter()
+            .all(|row| row[1] == row[0] + 1 && row[2] == row[0] + 2));
+        rows.sort();
+        assert_eq!(rows[3], vec![9, 10, 11]);
+        assert!(rng.shuffle_axis(&mut a, 2).is_err());
+
+        Ok(())
+    }
+}
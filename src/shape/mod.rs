@@ -1,7 +1,7 @@
 mod signed_shape;
 pub use signed_shape::SignedShape;
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 use core::{
     fmt::Debug,
@@ -9,7 +9,9 @@ use core::{
     ops::{Index, IndexMut},
 };
 
-use crate::{Dimensionality, DynDim, NDims, Order};
+#[cfg(feature = "alloc")]
+use crate::DynDim;
+use crate::{Dimensionality, NDims, Order};
 
 pub trait Shape:
     AsRef<[usize]>
@@ -66,6 +68,7 @@ impl<const N: usize> Shape for [usize; N] {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl Shape for Vec<usize> {
     type Dimensionality = DynDim;
     type Strides = Vec<isize>;
@@ -94,7 +97,7 @@ impl Shape for Vec<usize> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     #[cfg(not(feature = "std"))]
     use alloc::vec;
@@ -1,4 +1,4 @@
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 use core::{
     fmt::Debug,
@@ -6,7 +6,9 @@ use core::{
     ops::{Index, IndexMut},
 };
 
-use crate::{Dimensionality, DynDim, NDims};
+#[cfg(feature = "alloc")]
+use crate::DynDim;
+use crate::{Dimensionality, NDims};
 pub trait SignedShape:
     AsRef<[isize]>
     + AsMut<[isize]>
@@ -30,6 +32,7 @@ impl<const N: usize> SignedShape for [isize; N] {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl SignedShape for Vec<isize> {
     type Dimensionality = DynDim;
 
@@ -3,7 +3,11 @@ use core::{
     ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
 };
 
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize};
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Slice {
     pub(crate) start: Bound<isize>,
     pub(crate) end: Bound<isize>,
@@ -136,6 +140,32 @@ impl Slice {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Slice {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "Slice")]
+        struct SliceRepr {
+            start: Bound<isize>,
+            end: Bound<isize>,
+            step: isize,
+        }
+
+        let repr = SliceRepr::deserialize(deserializer)?;
+        if repr.step == 0 {
+            return Err(de::Error::custom("slice step cannot be zero"));
+        }
+        Ok(Self {
+            start: repr.start,
+            end: repr.end,
+            step: repr.step,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::{num::NonZeroIsize, ops::Bound};
@@ -466,4 +496,58 @@ mod tests {
             assert_eq!(s.start_with_dim(dim), dim as isize, "start: {}", start);
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_and_deserialize() -> Result<()> {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        assert_tokens(
+            &Slice::from(1..).step_by((-2).try_into()?),
+            &[
+                Token::Struct {
+                    name: "Slice",
+                    len: 3,
+                },
+                Token::Str("start"),
+                Token::NewtypeVariant {
+                    name: "Bound",
+                    variant: "Included",
+                },
+                Token::I64(1),
+                Token::Str("end"),
+                Token::UnitVariant {
+                    name: "Bound",
+                    variant: "Unbounded",
+                },
+                Token::Str("step"),
+                Token::I64(-2),
+                Token::StructEnd,
+            ],
+        );
+        assert_de_tokens_error::<Slice>(
+            &[
+                Token::Struct {
+                    name: "Slice",
+                    len: 3,
+                },
+                Token::Str("start"),
+                Token::UnitVariant {
+                    name: "Bound",
+                    variant: "Unbounded",
+                },
+                Token::Str("end"),
+                Token::UnitVariant {
+                    name: "Bound",
+                    variant: "Unbounded",
+                },
+                Token::Str("step"),
+                Token::I64(0),
+                Token::StructEnd,
+            ],
+            "slice step cannot be zero",
+        );
+
+        Ok(())
+    }
 }
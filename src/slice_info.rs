@@ -1,16 +1,23 @@
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 
-use crate::{ArrayIndex, DimensionalityDiff, DynDimDiff};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+#[cfg(feature = "alloc")]
+use crate::DynDimDiff;
+use crate::{ArrayIndex, DimensionalityDiff};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct SliceInfo<T, D>
 where
     D: DimensionalityDiff,
 {
     pub dim_diff: isize,
     pub indices: T,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub phantom: PhantomData<D>,
 }
 
@@ -24,21 +31,65 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<Vec<ArrayIndex>> for SliceInfo<Vec<ArrayIndex>, DynDimDiff> {
     fn from(indices: Vec<ArrayIndex>) -> Self {
-        let dim_diff = indices.iter().fold(0_isize, |acc, index| match index {
-            ArrayIndex::Index(_) => acc - 1,
-            ArrayIndex::Slice(_) => acc,
-            ArrayIndex::NewAxis => acc + 1,
-        });
         Self {
-            dim_diff,
+            dim_diff: count_dim_diff(&indices),
             indices,
             phantom: PhantomData,
         }
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de, D, T> Deserialize<'de> for SliceInfo<T, D>
+where
+    D: DimensionalityDiff,
+    T: AsRef<[ArrayIndex]> + Deserialize<'de>,
+{
+    fn deserialize<De>(deserializer: De) -> core::result::Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename = "SliceInfo")]
+        struct SliceInfoRepr<T> {
+            dim_diff: isize,
+            indices: T,
+        }
+
+        let repr = SliceInfoRepr::<T>::deserialize(deserializer)?;
+        let dim_diff = count_dim_diff(repr.indices.as_ref());
+        if repr.dim_diff != dim_diff {
+            return Err(de::Error::custom(format_args!(
+                "dim_diff is {}, but indices change the number of dimensions by {}",
+                repr.dim_diff, dim_diff
+            )));
+        }
+        match D::DIFF {
+            Some(expected) if expected != dim_diff => Err(de::Error::custom(format_args!(
+                "expected indices changing the number of dimensions by {}, but got {}",
+                expected, dim_diff
+            ))),
+            _ => Ok(Self {
+                dim_diff,
+                indices: repr.indices,
+                phantom: PhantomData,
+            }),
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "serde"))]
+fn count_dim_diff(indices: &[ArrayIndex]) -> isize {
+    indices.iter().fold(0_isize, |acc, index| match index {
+        ArrayIndex::Index(_) => acc - 1,
+        ArrayIndex::Slice(_) => acc,
+        ArrayIndex::NewAxis => acc + 1,
+    })
+}
+
 #[macro_export]
 macro_rules! s {
     (@fold $diff:expr, [$( $idx:tt )*] $r:expr;$s:expr) => {
@@ -137,7 +188,7 @@ macro_rules! dyn_s {
     };
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use core::num::NonZeroIsize;
 
@@ -269,4 +320,56 @@ mod tests {
         assert_eq!(info.indices.len(), 1);
         assert_eq!(info.indices[0], ArrayIndex::NewAxis);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_and_deserialize() {
+        #[cfg(not(feature = "std"))]
+        use alloc::vec::Vec;
+        use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+        fn tokens(dim_diff: i64) -> [Token; 10] {
+            [
+                Token::Struct {
+                    name: "SliceInfo",
+                    len: 2,
+                },
+                Token::Str("dim_diff"),
+                Token::I64(dim_diff),
+                Token::Str("indices"),
+                Token::Tuple { len: 2 },
+                Token::NewtypeVariant {
+                    name: "ArrayIndex",
+                    variant: "Index",
+                },
+                Token::I64(1),
+                Token::UnitVariant {
+                    name: "ArrayIndex",
+                    variant: "NewAxis",
+                },
+                Token::TupleEnd,
+                Token::StructEnd,
+            ]
+        }
+
+        const INFO: SliceInfo<[ArrayIndex; 2], DimDiff<0>> = s!(1, NewAxis);
+
+        assert_tokens(&INFO, &tokens(0));
+        assert_de_tokens(
+            &SliceInfo::<Vec<ArrayIndex>, DynDimDiff>::from(vec![1.into(), NewAxis.into()]),
+            &tokens(0).map(|token| match token {
+                Token::Tuple { len } => Token::Seq { len: Some(len) },
+                Token::TupleEnd => Token::SeqEnd,
+                token => token,
+            }),
+        );
+        assert_de_tokens_error::<SliceInfo<[ArrayIndex; 2], DimDiff<1>>>(
+            &tokens(0),
+            "expected indices changing the number of dimensions by 1, but got 0",
+        );
+        assert_de_tokens_error::<SliceInfo<[ArrayIndex; 2], DimDiff<0>>>(
+            &tokens(1),
+            "dim_diff is 1, but indices change the number of dimensions by 0",
+        );
+    }
 }
@@ -0,0 +1,289 @@
+#[cfg(not(feature = "std"))]
+use alloc::{
+    alloc::{alloc, dealloc, handle_alloc_error},
+    borrow::Cow,
+    vec::Vec,
+};
+use core::{
+    alloc::Layout,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+    marker::PhantomData,
+    ptr::{self, NonNull},
+    slice,
+};
+#[cfg(feature = "std")]
+use std::{
+    alloc::{alloc, dealloc, handle_alloc_error},
+    borrow::Cow,
+};
+
+use num_traits::{One, Zero};
+
+use super::{Storage, StorageBase, StorageMut, StorageOwned};
+
+pub struct AlignedVec<T, const ALIGN: usize> {
+    ptr: NonNull<T>,
+    len: usize,
+    phantom: PhantomData<T>,
+}
+
+unsafe impl<T, const ALIGN: usize> Send for AlignedVec<T, ALIGN> where T: Send {}
+
+unsafe impl<T, const ALIGN: usize> Sync for AlignedVec<T, ALIGN> where T: Sync {}
+
+impl<T, const ALIGN: usize> AlignedVec<T, ALIGN> {
+    // Evaluated when `layout` is instantiated, so an invalid alignment fails the build.
+    const VALID_ALIGN: () = assert!(ALIGN.is_power_of_two(), "alignment must be a power of two");
+
+    fn layout(len: usize) -> Layout {
+        let () = Self::VALID_ALIGN;
+        Layout::array::<T>(len)
+            .and_then(|layout| layout.align_to(ALIGN))
+            .expect("capacity overflow")
+    }
+
+    fn allocate(len: usize) -> NonNull<T> {
+        let layout = Self::layout(len);
+        if layout.size() == 0 {
+            unsafe { NonNull::new_unchecked(layout.align() as *mut T) }
+        } else {
+            let ptr = unsafe { alloc(layout) } as *mut T;
+            NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
+        }
+    }
+
+    fn filled(len: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let ptr = Self::allocate(len);
+        for i in 0..len {
+            unsafe { ptr::write(ptr.as_ptr().add(i), value.clone()) };
+        }
+        Self {
+            ptr,
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T, const ALIGN: usize> Clone for AlignedVec<T, ALIGN>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        self.as_slice().iter().cloned().collect()
+    }
+}
+
+impl<T, const ALIGN: usize> fmt::Debug for AlignedVec<T, ALIGN>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T, const ALIGN: usize> Drop for AlignedVec<T, ALIGN> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len));
+            let layout = Self::layout(self.len);
+            if layout.size() != 0 {
+                dealloc(self.ptr.as_ptr() as *mut u8, layout);
+            }
+        }
+    }
+}
+
+impl<T, const ALIGN: usize> Eq for AlignedVec<T, ALIGN> where T: Eq {}
+
+impl<T, const ALIGN: usize> From<Vec<T>> for AlignedVec<T, ALIGN> {
+    fn from(mut data: Vec<T>) -> Self {
+        let len = data.len();
+        let ptr = Self::allocate(len);
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), ptr.as_ptr(), len);
+            data.set_len(0);
+        }
+        Self {
+            ptr,
+            len,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, const ALIGN: usize> FromIterator<T> for AlignedVec<T, ALIGN> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T, const ALIGN: usize> Hash for AlignedVec<T, ALIGN>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T, const ALIGN: usize> PartialEq for AlignedVec<T, ALIGN>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, const ALIGN: usize> From<AlignedVec<T, ALIGN>> for StorageBase<AlignedVec<T, ALIGN>> {
+    fn from(data: AlignedVec<T, ALIGN>) -> Self {
+        Self(data)
+    }
+}
+
+impl<T, const ALIGN: usize> From<Vec<T>> for StorageBase<AlignedVec<T, ALIGN>> {
+    fn from(data: Vec<T>) -> Self {
+        Self(AlignedVec::from(data))
+    }
+}
+
+impl<T, const ALIGN: usize> FromIterator<T> for StorageBase<AlignedVec<T, ALIGN>> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(FromIterator::from_iter(iter))
+    }
+}
+
+impl<T, const ALIGN: usize> Storage for StorageBase<AlignedVec<T, ALIGN>>
+where
+    T: Clone,
+{
+    type Elem = T;
+    type Owned = StorageBase<AlignedVec<T, ALIGN>>;
+
+    fn as_ptr(&self) -> *const <Self as Storage>::Elem {
+        self.0.ptr.as_ptr()
+    }
+
+    fn as_slice(&self) -> &[<Self as Storage>::Elem] {
+        self.0.as_slice()
+    }
+
+    fn cow(&self) -> <Self as Storage>::Cow<'_> {
+        StorageBase(Cow::Borrowed(self.0.as_slice()))
+    }
+
+    fn view(&self) -> <Self as Storage>::View<'_> {
+        StorageBase(self.0.as_slice())
+    }
+}
+
+impl<T, const ALIGN: usize> StorageMut for StorageBase<AlignedVec<T, ALIGN>>
+where
+    T: Clone,
+{
+    fn as_mut_ptr(&mut self) -> *mut <Self as Storage>::Elem {
+        self.0.ptr.as_ptr()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [<Self as Storage>::Elem] {
+        self.0.as_mut_slice()
+    }
+
+    fn view_mut(&mut self) -> <Self as StorageMut>::ViewMut<'_> {
+        StorageBase(self.0.as_mut_slice())
+    }
+}
+
+impl<T, const ALIGN: usize> StorageOwned for StorageBase<AlignedVec<T, ALIGN>>
+where
+    T: Clone,
+{
+    fn allocate_uninitialized(len: usize) -> Self {
+        StorageBase(AlignedVec {
+            ptr: AlignedVec::<T, ALIGN>::allocate(len),
+            len,
+            phantom: PhantomData,
+        })
+    }
+
+    fn ones(len: usize) -> Self
+    where
+        <Self as Storage>::Elem: One,
+    {
+        StorageBase(AlignedVec::filled(len, T::one()))
+    }
+
+    fn zeros(len: usize) -> Self
+    where
+        <Self as Storage>::Elem: Zero,
+    {
+        StorageBase(AlignedVec::filled(len, T::zero()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::{vec, vec::Vec};
+
+    use super::AlignedVec;
+    use crate::{
+        storage::{Storage, StorageBase},
+        AlignedArray, ArrayBase, NDArray, NDArrayOwned, NDims,
+    };
+
+    #[test]
+    fn allocate_aligned_buffer() {
+        for len in [0, 1, 3, 17, 1024] {
+            let a = AlignedArray::<f32, _, 64>::zeros(&[len]);
+
+            assert_eq!(a.as_ptr() as usize % 64, 0);
+            assert_eq!(a.len(), len);
+            assert!(a.iter().all(|&x| x == 0.));
+        }
+    }
+
+    #[test]
+    fn collect_into_aligned_buffer() {
+        let storage = (0_u8..5).collect::<StorageBase<AlignedVec<_, 128>>>();
+
+        assert_eq!(storage.as_ptr() as usize % 128, 0);
+        assert_eq!(storage.as_slice(), &[0, 1, 2, 3, 4]);
+        assert_eq!(storage.clone(), storage);
+    }
+
+    #[test]
+    fn keep_alignment_through_operations() {
+        let a: AlignedArray<f64, NDims<2>, 32> =
+            ArrayBase::<StorageBase<AlignedVec<_, 32>>, _>::ones(&[3, 5]);
+        let b = &a + &a;
+        let c = a.to_owned_array();
+
+        assert_eq!(b.as_ptr() as usize % 32, 0);
+        assert!(b.iter().all(|&x| x == 2.));
+        assert_eq!(c.as_ptr() as usize % 32, 0);
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn drop_elements() {
+        let storage = StorageBase::<AlignedVec<_, 64>>::from(vec![vec![1_usize; 4]; 3]);
+
+        assert_eq!(storage.as_slice().iter().map(Vec::len).sum::<usize>(), 12);
+    }
+}
@@ -6,6 +6,15 @@ use std::borrow::Cow;
 
 use super::{Storage, StorageBase, StorageMut};
 
+impl<'a, T> From<Cow<'a, [T]>> for StorageBase<Cow<'a, [T]>>
+where
+    T: Clone,
+{
+    fn from(data: Cow<'a, [T]>) -> Self {
+        Self(data)
+    }
+}
+
 impl<T> FromIterator<T> for StorageBase<Cow<'_, [T]>>
 where
     T: Clone,
@@ -0,0 +1,398 @@
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FromIterator,
+    mem::MaybeUninit,
+    ptr, slice,
+};
+
+use num_traits::{One, Zero};
+
+use super::{Storage, StorageMut, StorageOwned};
+
+/// Storage for up to `N` elements kept inline, without allocating.
+///
+/// Views, copies and results of operations on arrays with this storage are
+/// inline too, and hold at most `N` elements. Collecting more elements than
+/// that panics; the `try_*` constructors of [`NDArrayOwned`] and the
+/// `*_with_order` operations return an error instead.
+///
+/// [`NDArrayOwned`]: crate::NDArrayOwned
+pub struct InlineStorage<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> InlineStorage<T, N> {
+    fn new() -> Self {
+        Self {
+            data: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    fn filled(len: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut storage = Self::new();
+        for _ in 0..len {
+            storage.push(value.clone());
+        }
+        storage
+    }
+
+    fn push(&mut self, value: T) {
+        assert!(
+            self.len < N,
+            "inline storage cannot hold more than {} elements",
+            N
+        );
+        self.data[self.len] = MaybeUninit::new(value);
+        self.len += 1;
+    }
+
+    fn slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.data.as_ptr() as *const T, self.len) }
+    }
+
+    fn slice_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.len) }
+    }
+}
+
+impl<T, const N: usize> Clone for InlineStorage<T, N>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        self.slice().iter().cloned().collect()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for InlineStorage<T, N>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.slice()).finish()
+    }
+}
+
+impl<T, const N: usize> Drop for InlineStorage<T, N> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.slice_mut()) };
+    }
+}
+
+impl<T, const N: usize> Eq for InlineStorage<T, N> where T: Eq {}
+
+impl<T, const N: usize> From<[T; N]> for InlineStorage<T, N> {
+    fn from(data: [T; N]) -> Self {
+        Self {
+            data: data.map(MaybeUninit::new),
+            len: N,
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for InlineStorage<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut storage = Self::new();
+        for value in iter {
+            storage.push(value);
+        }
+        storage
+    }
+}
+
+impl<T, const N: usize> Hash for InlineStorage<T, N>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.slice().hash(state)
+    }
+}
+
+impl<T, const N: usize> PartialEq for InlineStorage<T, N>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.slice() == other.slice()
+    }
+}
+
+impl<T, const N: usize> Storage for InlineStorage<T, N>
+where
+    T: Clone,
+{
+    type Elem = T;
+    type Cow<'a>
+        = InlineStorage<T, N>
+    where
+        T: 'a;
+    type Owned = InlineStorage<T, N>;
+    type Shared = InlineStorage<T, N>;
+    type View<'a>
+        = InlineView<'a, T, N>
+    where
+        T: 'a;
+
+    fn as_ptr(&self) -> *const <Self as Storage>::Elem {
+        self.data.as_ptr() as *const T
+    }
+
+    fn as_slice(&self) -> &[<Self as Storage>::Elem] {
+        self.slice()
+    }
+
+    fn cow(&self) -> <Self as Storage>::Cow<'_> {
+        self.clone()
+    }
+
+    fn view(&self) -> <Self as Storage>::View<'_> {
+        InlineView(self.slice())
+    }
+}
+
+impl<T, const N: usize> StorageMut for InlineStorage<T, N>
+where
+    T: Clone,
+{
+    type ViewMut<'a>
+        = InlineViewMut<'a, T, N>
+    where
+        T: 'a;
+
+    fn as_mut_ptr(&mut self) -> *mut <Self as Storage>::Elem {
+        self.data.as_mut_ptr() as *mut T
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [<Self as Storage>::Elem] {
+        self.slice_mut()
+    }
+
+    fn view_mut(&mut self) -> <Self as StorageMut>::ViewMut<'_> {
+        InlineViewMut(self.slice_mut())
+    }
+}
+
+impl<T, const N: usize> StorageOwned for InlineStorage<T, N>
+where
+    T: Clone,
+{
+    const CAPACITY: usize = N;
+
+    fn allocate_uninitialized(len: usize) -> Self {
+        assert!(
+            len <= N,
+            "inline storage cannot hold more than {} elements",
+            N
+        );
+        let mut storage = Self::new();
+        storage.len = len;
+        storage
+    }
+
+    fn ones(len: usize) -> Self
+    where
+        <Self as Storage>::Elem: One,
+    {
+        Self::filled(len, T::one())
+    }
+
+    fn zeros(len: usize) -> Self
+    where
+        <Self as Storage>::Elem: Zero,
+    {
+        Self::filled(len, T::zero())
+    }
+}
+
+/// View of an [`InlineStorage`], which copies back into inline storage.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct InlineView<'a, T, const N: usize>(&'a [T]);
+
+impl<T, const N: usize> Storage for InlineView<'_, T, N>
+where
+    T: Clone,
+{
+    type Elem = T;
+    type Cow<'a>
+        = InlineStorage<T, N>
+    where
+        Self: 'a;
+    type Owned = InlineStorage<T, N>;
+    type Shared = InlineStorage<T, N>;
+    type View<'a>
+        = InlineView<'a, T, N>
+    where
+        Self: 'a;
+
+    fn as_ptr(&self) -> *const <Self as Storage>::Elem {
+        self.0.as_ptr()
+    }
+
+    fn as_slice(&self) -> &[<Self as Storage>::Elem] {
+        self.0
+    }
+
+    fn cow(&self) -> <Self as Storage>::Cow<'_> {
+        self.0.iter().cloned().collect()
+    }
+
+    fn view(&self) -> <Self as Storage>::View<'_> {
+        InlineView(self.0)
+    }
+}
+
+/// Mutable view of an [`InlineStorage`].
+#[derive(Debug, Hash, Eq, PartialEq)]
+pub struct InlineViewMut<'a, T, const N: usize>(&'a mut [T]);
+
+impl<T, const N: usize> Storage for InlineViewMut<'_, T, N>
+where
+    T: Clone,
+{
+    type Elem = T;
+    type Cow<'a>
+        = InlineStorage<T, N>
+    where
+        Self: 'a;
+    type Owned = InlineStorage<T, N>;
+    type Shared = InlineStorage<T, N>;
+    type View<'a>
+        = InlineView<'a, T, N>
+    where
+        Self: 'a;
+
+    fn as_ptr(&self) -> *const <Self as Storage>::Elem {
+        self.0.as_ptr()
+    }
+
+    fn as_slice(&self) -> &[<Self as Storage>::Elem] {
+        self.0
+    }
+
+    fn cow(&self) -> <Self as Storage>::Cow<'_> {
+        self.0.iter().cloned().collect()
+    }
+
+    fn view(&self) -> <Self as Storage>::View<'_> {
+        InlineView(self.0)
+    }
+}
+
+impl<T, const N: usize> StorageMut for InlineViewMut<'_, T, N>
+where
+    T: Clone,
+{
+    type ViewMut<'a>
+        = InlineViewMut<'a, T, N>
+    where
+        Self: 'a;
+
+    fn as_mut_ptr(&mut self) -> *mut <Self as Storage>::Elem {
+        self.0.as_mut_ptr()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [<Self as Storage>::Elem] {
+        self.0
+    }
+
+    fn view_mut(&mut self) -> <Self as StorageMut>::ViewMut<'_> {
+        InlineViewMut(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InlineStorage, InlineView};
+    use crate::{
+        storage::Storage, ArrayBase, Dot, Error, NDArray, NDArrayOwned, NDims, RowMajor, ShapeError,
+    };
+
+    #[test]
+    fn collect_into_inline_storage() {
+        let storage = (0..3).collect::<InlineStorage<_, 4>>();
+
+        assert_eq!(storage.as_slice(), &[0, 1, 2]);
+        assert_eq!(storage.clone(), storage);
+    }
+
+    #[test]
+    #[should_panic]
+    fn collect_too_many_elements() {
+        let _: InlineStorage<_, 4> = (0..5).collect();
+    }
+
+    #[test]
+    fn operate_on_inline_arrays() {
+        let a = ArrayBase::from([1_f32, 2., 3., 4.])
+            .into_shape([2, 2])
+            .unwrap();
+        let b = ArrayBase::<InlineStorage<f32, 4>, NDims<2>>::ones(&[2, 2]);
+        let sum: ArrayBase<InlineStorage<f32, 4>, _> = &a + &b;
+        let product: ArrayBase<InlineStorage<f32, 4>, _> = a.dot(&b);
+
+        assert!(
+            sum == ArrayBase::from([2., 3., 4., 5.])
+                .into_shape([2, 2])
+                .unwrap()
+        );
+        assert!(
+            product
+                == ArrayBase::from([3., 3., 7., 7.])
+                    .into_shape([2, 2])
+                    .unwrap()
+        );
+    }
+
+    #[test]
+    fn keep_derived_arrays_inline() {
+        let a = ArrayBase::from([1, 2, 3, 4]);
+        let view: ArrayBase<InlineView<'_, i32, 4>, _> = a.view();
+        let reshaped: ArrayBase<InlineStorage<i32, 4>, _> = a.to_shape([2, 2]).unwrap();
+        let owned: ArrayBase<InlineStorage<i32, 4>, _> = view.transpose().to_owned_array();
+
+        assert_eq!(reshaped.shape(), &[2, 2]);
+        assert!(owned.iter().eq(a.iter()));
+    }
+
+    #[test]
+    fn exceed_capacity() {
+        let a = ArrayBase::from([1_f32, 2., 3., 4.]);
+        let b = ArrayBase::from([1_f32, 2., 3., 4.])
+            .into_shape([4, 1])
+            .unwrap();
+
+        assert!(ArrayBase::<InlineStorage<f32, 4>, NDims<2>>::try_zeros(&[2, 2]).is_ok());
+        assert!(matches!(
+            ArrayBase::<InlineStorage<f32, 4>, NDims<2>>::try_zeros(&[2, 3]),
+            Err(Error::Shape(ShapeError::IncompatibleShape(_)))
+        ));
+        assert!(matches!(
+            ArrayBase::<InlineStorage<f32, 4>, NDims<1>>::try_full(&[5], 1.),
+            Err(Error::Shape(_))
+        ));
+        assert!(matches!(
+            b.add_with_order::<RowMajor, _>(&a),
+            Err(Error::Shape(_))
+        ));
+        assert!(matches!(
+            b.dot_with_order::<RowMajor, _>(&a.to_shape([1, 4]).unwrap()),
+            Err(Error::Shape(_))
+        ));
+    }
+
+    #[test]
+    fn transform_3_vector() {
+        let m = ArrayBase::<InlineStorage<f64, 16>, NDims<2>>::zeros(&[4, 4]);
+        let v = ArrayBase::from([1_f64, 2., 3., 1.]);
+
+        assert!(m.dot(&v).iter().all(|&x| x == 0.));
+        assert_eq!((-v).shape(), &[4]);
+    }
+}
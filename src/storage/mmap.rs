@@ -0,0 +1,289 @@
+use core::{fmt, marker::PhantomData, mem, slice};
+use std::{borrow::Cow, fs::File};
+
+use memmap2::{Mmap as RawMmap, MmapMut as RawMmapMut};
+
+use super::{Storage, StorageBase, StorageMut};
+use crate::{Error, Result};
+
+pub struct Mmap<T> {
+    map: RawMmap,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Mmap<T>
+where
+    T: Copy,
+{
+    /// Maps the whole `file` read-only as a sequence of `T`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified while it is mapped, and its contents
+    /// must be valid values of `T`.
+    pub unsafe fn map(file: &File) -> Result<Self> {
+        let map = RawMmap::map(file)?;
+        check_layout::<T>(map.as_ptr(), map.len())?;
+        Ok(Self {
+            map,
+            phantom: PhantomData,
+        })
+    }
+
+    fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.map.as_ptr() as *const T, self.len()) }
+    }
+
+    fn len(&self) -> usize {
+        self.map.len() / mem::size_of::<T>()
+    }
+}
+
+impl<T> fmt::Debug for Mmap<T>
+where
+    T: Copy + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+pub struct MmapMut<T> {
+    map: RawMmapMut,
+    phantom: PhantomData<T>,
+}
+
+impl<T> MmapMut<T>
+where
+    T: Copy,
+{
+    /// Maps the whole `file` read-write as a sequence of `T`. Changes are
+    /// written back to the file.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified by others while it is mapped, and its
+    /// contents must be valid values of `T`.
+    pub unsafe fn map(file: &File) -> Result<Self> {
+        let map = RawMmapMut::map_mut(file)?;
+        check_layout::<T>(map.as_ptr(), map.len())?;
+        Ok(Self {
+            map,
+            phantom: PhantomData,
+        })
+    }
+
+    pub fn flush(&self) -> Result<()> {
+        self.map.flush()?;
+        Ok(())
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.map.as_mut_ptr() as *mut T, self.len()) }
+    }
+
+    fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.map.as_ptr() as *const T, self.len()) }
+    }
+
+    fn len(&self) -> usize {
+        self.map.len() / mem::size_of::<T>()
+    }
+}
+
+impl<T> fmt::Debug for MmapMut<T>
+where
+    T: Copy + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+fn check_layout<T>(ptr: *const u8, len: usize) -> Result<()> {
+    let size = mem::size_of::<T>();
+    if size == 0 {
+        return Err(Error::Value(
+            "zero-sized types cannot be memory-mapped".into(),
+        ));
+    }
+    if !len.is_multiple_of(size) {
+        return Err(Error::Value(format!(
+            "file size {} is not a multiple of element size {}",
+            len, size
+        )));
+    }
+    if len > 0 && !(ptr as usize).is_multiple_of(mem::align_of::<T>()) {
+        return Err(Error::Value(
+            "mapped memory is not aligned for the element type".into(),
+        ));
+    }
+    Ok(())
+}
+
+impl<T> From<Mmap<T>> for StorageBase<Mmap<T>> {
+    fn from(data: Mmap<T>) -> Self {
+        Self(data)
+    }
+}
+
+impl<T> Storage for StorageBase<Mmap<T>>
+where
+    T: Copy,
+{
+    type Elem = T;
+
+    fn as_ptr(&self) -> *const <Self as Storage>::Elem {
+        self.0.as_slice().as_ptr()
+    }
+
+    fn as_slice(&self) -> &[<Self as Storage>::Elem] {
+        self.0.as_slice()
+    }
+
+    fn cow(&self) -> <Self as Storage>::Cow<'_> {
+        StorageBase(Cow::Borrowed(self.0.as_slice()))
+    }
+
+    fn view(&self) -> <Self as Storage>::View<'_> {
+        StorageBase(self.0.as_slice())
+    }
+}
+
+impl<T> From<MmapMut<T>> for StorageBase<MmapMut<T>> {
+    fn from(data: MmapMut<T>) -> Self {
+        Self(data)
+    }
+}
+
+impl<T> Storage for StorageBase<MmapMut<T>>
+where
+    T: Copy,
+{
+    type Elem = T;
+
+    fn as_ptr(&self) -> *const <Self as Storage>::Elem {
+        self.0.as_slice().as_ptr()
+    }
+
+    fn as_slice(&self) -> &[<Self as Storage>::Elem] {
+        self.0.as_slice()
+    }
+
+    fn cow(&self) -> <Self as Storage>::Cow<'_> {
+        StorageBase(Cow::Borrowed(self.0.as_slice()))
+    }
+
+    fn view(&self) -> <Self as Storage>::View<'_> {
+        StorageBase(self.0.as_slice())
+    }
+}
+
+impl<T> StorageMut for StorageBase<MmapMut<T>>
+where
+    T: Copy,
+{
+    fn as_mut_ptr(&mut self) -> *mut <Self as Storage>::Elem {
+        self.0.as_mut_slice().as_mut_ptr()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [<Self as Storage>::Elem] {
+        self.0.as_mut_slice()
+    }
+
+    fn view_mut(&mut self) -> <Self as StorageMut>::ViewMut<'_> {
+        StorageBase(self.0.as_mut_slice())
+    }
+}
+
+impl<T> StorageBase<MmapMut<T>>
+where
+    T: Copy,
+{
+    pub(crate) fn flush(&self) -> Result<()> {
+        self.0.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::{self, File, OpenOptions},
+        io::Write,
+        path::PathBuf,
+    };
+
+    use super::{Mmap, MmapMut};
+    use crate::{s, ArrayBase, NDArray, NDArrayMut, Result};
+
+    fn create_file(name: &str, data: &[f64]) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("tamahagane-{}-{}.bin", name, std::process::id()));
+        let mut file = File::create(&path).unwrap();
+        for x in data {
+            file.write_all(&x.to_ne_bytes()).unwrap();
+        }
+        path
+    }
+
+    #[test]
+    fn map_read_only() -> Result<()> {
+        let data = (0..24).map(|x| x as f64).collect::<Vec<_>>();
+        let path = create_file("map_read_only", &data);
+        {
+            let file = File::open(&path)?;
+            let a1 = ArrayBase::from(unsafe { Mmap::<f64>::map(&file)? });
+            let a3 = a1.to_shape([2, 3, 4])?;
+
+            assert_eq!(a3.shape(), &[2, 3, 4]);
+            assert_eq!(a3.as_ptr(), a1.as_ptr());
+
+            let sliced = a3.slice(s!(1, .., ..;2));
+
+            assert_eq!(
+                sliced.iter().cloned().collect::<Vec<_>>(),
+                vec![12., 14., 16., 18., 20., 22.]
+            );
+            assert_eq!(a1.iter().sum::<f64>(), data.iter().sum::<f64>());
+        }
+        fs::remove_file(path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn map_read_write() -> Result<()> {
+        let path = create_file("map_read_write", &[0.; 8]);
+        {
+            let file = OpenOptions::new().read(true).write(true).open(&path)?;
+            let mut a1 = ArrayBase::from(unsafe { MmapMut::<f64>::map(&file)? });
+            a1.slice_mut(s!(..;2)).fill(1.);
+            a1.flush()?;
+        }
+        {
+            let file = File::open(&path)?;
+            let a1 = ArrayBase::from(unsafe { Mmap::<f64>::map(&file)? });
+
+            assert_eq!(
+                a1.iter().cloned().collect::<Vec<_>>(),
+                vec![1., 0., 1., 0., 1., 0., 1., 0.]
+            );
+        }
+        fs::remove_file(path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn map_file_of_invalid_size() -> Result<()> {
+        let path = create_file("map_file_of_invalid_size", &[0.; 3]);
+        {
+            let file = File::open(&path)?;
+
+            assert!(unsafe { Mmap::<[f64; 2]>::map(&file) }.is_err());
+        }
+        fs::remove_file(path)?;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,25 @@
+#[cfg(feature = "alloc")]
+mod aligned;
+#[cfg(feature = "alloc")]
+pub use aligned::AlignedVec;
+#[cfg(feature = "alloc")]
 mod cow;
+mod inline;
+pub use inline::{InlineStorage, InlineView, InlineViewMut};
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "mmap")]
+pub use mmap::{Mmap, MmapMut};
+#[cfg(feature = "alloc")]
 mod owned;
+#[cfg(feature = "alloc")]
 mod routine;
+#[cfg(feature = "alloc")]
 mod shared;
+#[cfg(feature = "alloc")]
 mod view;
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
 use alloc::{borrow::Cow, sync::Arc, vec::Vec};
 use core::iter::FromIterator;
 #[cfg(feature = "std")]
@@ -12,23 +27,45 @@ use std::{borrow::Cow, sync::Arc};
 
 use num_traits::{One, Zero};
 
+// Without an allocator there is no storage every other one can fall back to,
+// so the associated types have no defaults
 pub trait Storage {
     type Elem: Clone;
+    #[cfg(feature = "alloc")]
     type Cow<'a>: FromIterator<<Self as Storage>::Elem> + StorageMut<Elem = <Self as Storage>::Elem>
     where
         Self: 'a,
         <Self as Storage>::Elem: 'a,
     = StorageBase<Cow<'a, [<Self as Storage>::Elem]>>;
+    #[cfg(not(feature = "alloc"))]
+    type Cow<'a>: FromIterator<<Self as Storage>::Elem> + StorageMut<Elem = <Self as Storage>::Elem>
+    where
+        Self: 'a,
+        <Self as Storage>::Elem: 'a;
+    #[cfg(feature = "alloc")]
     type Owned: FromIterator<<Self as Storage>::Elem>
         + StorageMut<Elem = <Self as Storage>::Elem>
         + StorageOwned<Elem = <Self as Storage>::Elem> = StorageBase<Vec<<Self as Storage>::Elem>>;
+    #[cfg(not(feature = "alloc"))]
+    type Owned: FromIterator<<Self as Storage>::Elem>
+        + StorageMut<Elem = <Self as Storage>::Elem>
+        + StorageOwned<Elem = <Self as Storage>::Elem>;
+    #[cfg(feature = "alloc")]
     type Shared: StorageOwned<Elem = <Self as Storage>::Elem> =
         StorageBase<Arc<Vec<<Self as Storage>::Elem>>>;
+    #[cfg(not(feature = "alloc"))]
+    type Shared: StorageOwned<Elem = <Self as Storage>::Elem>;
+    #[cfg(feature = "alloc")]
     type View<'a>: Storage<Elem = <Self as Storage>::Elem>
     where
         Self: 'a,
         <Self as Storage>::Elem: 'a,
     = StorageBase<&'a [<Self as Storage>::Elem]>;
+    #[cfg(not(feature = "alloc"))]
+    type View<'a>: Storage<Elem = <Self as Storage>::Elem>
+    where
+        Self: 'a,
+        <Self as Storage>::Elem: 'a;
     fn as_ptr(&self) -> *const <Self as Storage>::Elem;
     fn as_slice(&self) -> &[<Self as Storage>::Elem];
     fn cow(&self) -> <Self as Storage>::Cow<'_>;
@@ -36,18 +73,27 @@ pub trait Storage {
 }
 
 pub trait StorageMut: Storage {
+    #[cfg(feature = "alloc")]
     type ViewMut<'a>: Storage<Elem = <Self as Storage>::Elem>
         + StorageMut<Elem = <Self as Storage>::Elem>
     where
         Self: 'a,
         <Self as Storage>::Elem: 'a,
     = StorageBase<&'a mut [<Self as Storage>::Elem]>;
+    #[cfg(not(feature = "alloc"))]
+    type ViewMut<'a>: Storage<Elem = <Self as Storage>::Elem>
+        + StorageMut<Elem = <Self as Storage>::Elem>
+    where
+        Self: 'a,
+        <Self as Storage>::Elem: 'a;
     fn as_mut_ptr(&mut self) -> *mut <Self as Storage>::Elem;
     fn as_mut_slice(&mut self) -> &mut [<Self as Storage>::Elem];
     fn view_mut(&mut self) -> <Self as StorageMut>::ViewMut<'_>;
 }
 
 pub trait StorageOwned: FromIterator<<Self as Storage>::Elem> + Storage {
+    /// Largest number of elements the storage can hold.
+    const CAPACITY: usize = usize::MAX;
     fn allocate_uninitialized(len: usize) -> Self;
     fn ones(len: usize) -> Self
     where